@@ -0,0 +1,63 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use lazy_static::lazy_static;
+use space_rpg::units::*;
+
+lazy_static! {
+    // The old path: the exact rational of each unit converted to a `DisplayValue` on first use.
+    static ref LAZY_FACTORS: Vec<DisplayValue> = LengthUnit::UNITS
+        .iter()
+        .map(|unit| DisplayValue::from_exact(&unit.exact_amount_of_base()))
+        .collect();
+}
+
+fn zero() -> DisplayValue {
+    DisplayValue::from_f64(0.0)
+}
+
+fn amount_of_base(c: &mut Criterion) {
+    let mut group = c.benchmark_group("amount_of_base");
+    group.bench_function("lazy_static", |b| {
+        b.iter(|| {
+            LengthUnit::UNITS
+                .iter()
+                .fold(zero(), |sum, unit| sum + LAZY_FACTORS[black_box(*unit) as usize])
+        })
+    });
+    group.bench_function("const", |b| {
+        b.iter(|| {
+            LengthUnit::UNITS.iter().fold(zero(), |sum, unit| {
+                sum + black_box(*unit).amount_of_base_as::<DisplayValue>()
+            })
+        })
+    });
+    group.finish();
+}
+
+fn convert(c: &mut Criterion) {
+    let values: Vec<DisplayValue> = (0..1000)
+        .map(|value| DisplayValue::from_f64(value as f64))
+        .collect();
+
+    let mut group = c.benchmark_group("convert_km_to_au");
+    group.bench_function("lazy_static", |b| {
+        b.iter(|| {
+            let from = LAZY_FACTORS[black_box(LengthUnit::Kilometer) as usize];
+            let to = LAZY_FACTORS[black_box(LengthUnit::AU) as usize];
+            values
+                .iter()
+                .fold(zero(), |sum, value| sum + *value * from / to)
+        })
+    });
+    group.bench_function("const", |b| {
+        b.iter(|| {
+            values.iter().fold(zero(), |sum, value| {
+                let base: DisplayValue = black_box(LengthUnit::Kilometer).base_repr(*value);
+                sum + black_box(LengthUnit::AU).display_repr(base)
+            })
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, amount_of_base, convert);
+criterion_main!(benches);
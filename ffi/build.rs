@@ -0,0 +1,20 @@
+use std::env;
+use std::path::PathBuf;
+
+fn main() {
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+
+    let bindings = cbindgen::Builder::new()
+        .with_crate(&crate_dir)
+        .with_config(cbindgen::Config::from_file(format!("{}/cbindgen.toml", crate_dir)).unwrap())
+        .generate()
+        .expect("Unable to generate space_rpg.h");
+    bindings.write_to_file(out_dir.join("space_rpg.h"));
+    // Only touches the source tree when asked to, e.g. to refresh the checked in header.
+    if env::var_os("CARGO_FEATURE_HEADER").is_some() {
+        bindings.write_to_file(format!("{}/include/space_rpg.h", crate_dir));
+    }
+}
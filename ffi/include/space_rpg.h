@@ -0,0 +1,145 @@
+#ifndef SPACE_RPG_H
+#define SPACE_RPG_H
+
+/* Generated by cbindgen from space_rpg_ffi, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum SpaceRpgStatus {
+  SPACE_RPG_STATUS_OK = 0,
+  SPACE_RPG_STATUS_NULL_POINTER,
+  SPACE_RPG_STATUS_INVALID_UNIT_ID,
+  SPACE_RPG_STATUS_UNKNOWN_SYMBOL,
+  SPACE_RPG_STATUS_DIMENSION_MISMATCH,
+  SPACE_RPG_STATUS_PARSE_ERROR,
+  SPACE_RPG_STATUS_BUFFER_TOO_SMALL,
+  SPACE_RPG_STATUS_OVERFLOW,
+} SpaceRpgStatus;
+
+typedef enum SpaceRpgUnitFamily {
+  SPACE_RPG_UNIT_FAMILY_TIME = 0,
+  SPACE_RPG_UNIT_FAMILY_MASS,
+  SPACE_RPG_UNIT_FAMILY_LENGTH,
+  SPACE_RPG_UNIT_FAMILY_ELECTRIC_CURRENT,
+} SpaceRpgUnitFamily;
+
+/**
+ * A standard unit, `family` is a `SpaceRpgUnitFamily` and `id` is the stable id of the unit
+ * within its family with 0 being the family's base unit.
+ */
+typedef struct SpaceRpgUnit {
+  uint32_t family;
+  uint32_t id;
+} SpaceRpgUnit;
+
+typedef struct SpaceRpgDims {
+  ptrdiff_t time;
+  ptrdiff_t length;
+  ptrdiff_t mass;
+  ptrdiff_t electric_current;
+  ptrdiff_t temperature;
+  ptrdiff_t amount;
+  ptrdiff_t luminous_intensity;
+} SpaceRpgDims;
+
+/**
+ * `status` is a `SpaceRpgStatus`, unknown values give `"unknown status"`.
+ */
+const char *space_rpg_status_message(uint32_t status);
+
+/**
+ * `family` is a `SpaceRpgUnitFamily`, unknown families have no units. Ids of the family are
+ * below the count.
+ */
+size_t space_rpg_unit_count(uint32_t family);
+
+/**
+ * `family` is a `SpaceRpgUnitFamily`.
+ *
+ * # Safety
+ * `out` must be null or valid for writes.
+ */
+enum SpaceRpgStatus space_rpg_unit_from_id(uint32_t family, uint32_t id, struct SpaceRpgUnit *out);
+
+/**
+ * # Safety
+ * `symbol` must be null or a NUL terminated UTF-8 string, `out` must be null or valid for writes.
+ */
+enum SpaceRpgStatus space_rpg_unit_from_symbol(const char *symbol, struct SpaceRpgUnit *out);
+
+/**
+ * Writes the NUL terminated UTF-8 symbol of `unit` into `buffer` of `length` bytes.
+ *
+ * # Safety
+ * `buffer` must be null or valid for `length` bytes of writes.
+ */
+enum SpaceRpgStatus space_rpg_unit_symbol(struct SpaceRpgUnit unit, char *buffer, size_t length);
+
+/**
+ * # Safety
+ * `out` must be null or valid for writes.
+ */
+enum SpaceRpgStatus space_rpg_unit_dims(struct SpaceRpgUnit unit, struct SpaceRpgDims *out);
+
+/**
+ * Converts `value` in `from` into `to`.
+ *
+ * # Safety
+ * `out` must be null or valid for writes.
+ */
+enum SpaceRpgStatus space_rpg_convert(double value,
+                                      struct SpaceRpgUnit from,
+                                      struct SpaceRpgUnit to,
+                                      double *out);
+
+/**
+ * Parses a quantity such as `12.5 km` into its value and unit.
+ *
+ * # Safety
+ * `text` must be null or a NUL terminated UTF-8 string, `value` and `unit` must be null or
+ * valid for writes.
+ */
+enum SpaceRpgStatus space_rpg_parse_quantity(const char *text,
+                                             double *value,
+                                             struct SpaceRpgUnit *unit);
+
+/**
+ * The dims of a product, [`SpaceRpgStatus::Overflow`] if an exponent overflows.
+ *
+ * # Safety
+ * `out` must be null or valid for writes.
+ */
+enum SpaceRpgStatus space_rpg_dims_mul(struct SpaceRpgDims lhs,
+                                       struct SpaceRpgDims rhs,
+                                       struct SpaceRpgDims *out);
+
+/**
+ * The dims of a quotient, [`SpaceRpgStatus::Overflow`] if an exponent overflows.
+ *
+ * # Safety
+ * `out` must be null or valid for writes.
+ */
+enum SpaceRpgStatus space_rpg_dims_div(struct SpaceRpgDims lhs,
+                                       struct SpaceRpgDims rhs,
+                                       struct SpaceRpgDims *out);
+
+/**
+ * The dims raised to `exponent`, [`SpaceRpgStatus::Overflow`] if an exponent overflows.
+ *
+ * # Safety
+ * `out` must be null or valid for writes.
+ */
+enum SpaceRpgStatus space_rpg_dims_pow(struct SpaceRpgDims dims,
+                                       ptrdiff_t exponent,
+                                       struct SpaceRpgDims *out);
+
+/**
+ * Returns [`SpaceRpgStatus::DimensionMismatch`] unless `lhs` and `rhs` are equal.
+ */
+enum SpaceRpgStatus space_rpg_dims_check(struct SpaceRpgDims lhs, struct SpaceRpgDims rhs);
+
+#endif /* SPACE_RPG_H */
@@ -0,0 +1,543 @@
+//! C ABI over [`space_rpg::units`], the header is generated into `OUT_DIR`, enable the `header`
+//! feature to also write it to `include/space_rpg.h`.
+//!
+//! Every fallible function returns a [`SpaceRpgStatus`] and writes its result through an out
+//! pointer, which is left untouched on failure. Enums are only ever returned, C passes them
+//! back in as `uint32_t` so that an out of range value can't be undefined behaviour.
+
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+use space_rpg::units::*;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SpaceRpgStatus {
+    Ok = 0,
+    NullPointer,
+    InvalidUnitId,
+    UnknownSymbol,
+    DimensionMismatch,
+    ParseError,
+    BufferTooSmall,
+    Overflow,
+}
+impl SpaceRpgStatus {
+    const ALL: [Self; 8] = [
+        SpaceRpgStatus::Ok,
+        SpaceRpgStatus::NullPointer,
+        SpaceRpgStatus::InvalidUnitId,
+        SpaceRpgStatus::UnknownSymbol,
+        SpaceRpgStatus::DimensionMismatch,
+        SpaceRpgStatus::ParseError,
+        SpaceRpgStatus::BufferTooSmall,
+        SpaceRpgStatus::Overflow,
+    ];
+
+    fn from_u32(status: u32) -> Option<Self> {
+        Self::ALL.get(status as usize).copied()
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SpaceRpgUnitFamily {
+    Time = 0,
+    Mass,
+    Length,
+    ElectricCurrent,
+}
+impl SpaceRpgUnitFamily {
+    const ALL: [Self; 4] = [
+        SpaceRpgUnitFamily::Time,
+        SpaceRpgUnitFamily::Mass,
+        SpaceRpgUnitFamily::Length,
+        SpaceRpgUnitFamily::ElectricCurrent,
+    ];
+
+    fn from_u32(family: u32) -> Option<Self> {
+        Self::ALL.get(family as usize).copied()
+    }
+}
+
+/// A standard unit, `family` is a `SpaceRpgUnitFamily` and `id` is the stable id of the unit
+/// within its family with 0 being the family's base unit.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct SpaceRpgUnit {
+    pub family: u32,
+    pub id: u32,
+}
+impl SpaceRpgUnit {
+    fn to_standard(self) -> Option<StandardUnit> {
+        unit_from_id(SpaceRpgUnitFamily::from_u32(self.family)?, self.id)
+    }
+}
+impl From<StandardUnit> for SpaceRpgUnit {
+    fn from(from: StandardUnit) -> Self {
+        let (family, id) = unit_id(from);
+        Self {
+            family: family as u32,
+            id,
+        }
+    }
+}
+
+// Ids are part of the ABI, they don't follow the order of the families and are never reused, a
+// new unit takes the next free id of its family. The match in `unit_id` fails to compile when a
+// unit has no id.
+macro_rules! unit_ids {
+    ($($family:ident($unit:ident) { $($id:literal => $variant:ident,)+ })+) => {
+        fn unit_from_id(family: SpaceRpgUnitFamily, id: u32) -> Option<StandardUnit> {
+            match family {
+                $(
+                    SpaceRpgUnitFamily::$family => match id {
+                        $($id => Some(StandardUnit::$family($unit::$variant)),)+
+                        _ => None,
+                    },
+                )+
+            }
+        }
+
+        fn unit_id(unit: StandardUnit) -> (SpaceRpgUnitFamily, u32) {
+            match unit {
+                $($(StandardUnit::$family($unit::$variant) => (SpaceRpgUnitFamily::$family, $id),)+)+
+            }
+        }
+    };
+}
+
+unit_ids! {
+    Time(TimeUnit) {
+        0 => Nanosecond,
+        1 => Microsecond,
+        2 => Millisecond,
+        3 => Second,
+        4 => Minute,
+        5 => Hour,
+        6 => GSDay,
+        7 => GSMonth,
+        8 => GSYear,
+        9 => GSCentury,
+        10 => GSMillennium,
+        11 => JulianYear,
+        12 => SiderealDay,
+        13 => Week,
+    }
+    Mass(MassUnit) {
+        0 => Milligram,
+        1 => Gram,
+        2 => Kilogram,
+        3 => Tonne,
+        4 => Oz,
+        5 => Pound,
+        6 => Ton,
+        7 => EarthMass,
+        8 => SolarMass,
+        9 => JupiterMass,
+        10 => LunarMass,
+        11 => AtomicMassUnit,
+    }
+    Length(LengthUnit) {
+        0 => NanoMeter,
+        1 => MicroMeter,
+        2 => MilliMeter,
+        3 => Centimeter,
+        4 => Decimeter,
+        5 => Meter,
+        6 => Kilometer,
+        7 => AU,
+        8 => LightYear,
+        9 => Parsec,
+        10 => Kiloparsec,
+        11 => Megameter,
+        12 => SolarRadius,
+        13 => EarthRadius,
+        14 => LunarDistance,
+        15 => Foot,
+        16 => Mile,
+        17 => NauticalMile,
+    }
+    ElectricCurrent(ElectricCurrentUnit) {
+        0 => Picoampere,
+        1 => Nanoampere,
+        2 => Microampere,
+        3 => Milliampere,
+        4 => Ampere,
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct SpaceRpgDims {
+    pub time: isize,
+    pub length: isize,
+    pub mass: isize,
+    pub electric_current: isize,
+    pub temperature: isize,
+    pub amount: isize,
+    pub luminous_intensity: isize,
+}
+impl From<UnitDims> for SpaceRpgDims {
+    fn from(from: UnitDims) -> Self {
+        Self {
+            time: from.time,
+            length: from.length,
+            mass: from.mass,
+            electric_current: from.electric_current,
+            temperature: from.temperature,
+            amount: from.amount,
+            luminous_intensity: from.luminous_intensity,
+        }
+    }
+}
+impl SpaceRpgDims {
+    /// Applies `op` to each pair of exponents, [`None`] if any of them overflows.
+    fn checked_zip(self, rhs: Self, op: fn(isize, isize) -> Option<isize>) -> Option<Self> {
+        Some(Self {
+            time: op(self.time, rhs.time)?,
+            length: op(self.length, rhs.length)?,
+            mass: op(self.mass, rhs.mass)?,
+            electric_current: op(self.electric_current, rhs.electric_current)?,
+            temperature: op(self.temperature, rhs.temperature)?,
+            amount: op(self.amount, rhs.amount)?,
+            luminous_intensity: op(self.luminous_intensity, rhs.luminous_intensity)?,
+        })
+    }
+}
+impl From<SpaceRpgDims> for UnitDims {
+    fn from(from: SpaceRpgDims) -> Self {
+        Self {
+            time: from.time,
+            length: from.length,
+            mass: from.mass,
+            electric_current: from.electric_current,
+            temperature: from.temperature,
+            amount: from.amount,
+            luminous_intensity: from.luminous_intensity,
+        }
+    }
+}
+
+macro_rules! try_status {
+    ($expr:expr, $status:expr) => {
+        match $expr {
+            Some(value) => value,
+            None => return $status,
+        }
+    };
+}
+
+/// `status` is a `SpaceRpgStatus`, unknown values give `"unknown status"`.
+#[no_mangle]
+pub extern "C" fn space_rpg_status_message(status: u32) -> *const c_char {
+    let message: &'static [u8] = match SpaceRpgStatus::from_u32(status) {
+        Some(SpaceRpgStatus::Ok) => b"ok\0",
+        Some(SpaceRpgStatus::NullPointer) => b"null pointer\0",
+        Some(SpaceRpgStatus::InvalidUnitId) => b"invalid unit id\0",
+        Some(SpaceRpgStatus::UnknownSymbol) => b"unknown unit symbol\0",
+        Some(SpaceRpgStatus::DimensionMismatch) => b"dimension mismatch\0",
+        Some(SpaceRpgStatus::ParseError) => b"could not parse quantity\0",
+        Some(SpaceRpgStatus::BufferTooSmall) => b"buffer too small\0",
+        Some(SpaceRpgStatus::Overflow) => b"dimension exponent overflow\0",
+        None => b"unknown status\0",
+    };
+    message.as_ptr() as *const c_char
+}
+
+/// `family` is a `SpaceRpgUnitFamily`, unknown families have no units. Ids of the family are
+/// below the count.
+#[no_mangle]
+pub extern "C" fn space_rpg_unit_count(family: u32) -> usize {
+    match SpaceRpgUnitFamily::from_u32(family) {
+        Some(SpaceRpgUnitFamily::Time) => TimeUnit::UNITS.len(),
+        Some(SpaceRpgUnitFamily::Mass) => MassUnit::UNITS.len(),
+        Some(SpaceRpgUnitFamily::Length) => LengthUnit::UNITS.len(),
+        Some(SpaceRpgUnitFamily::ElectricCurrent) => ElectricCurrentUnit::UNITS.len(),
+        None => 0,
+    }
+}
+
+/// `family` is a `SpaceRpgUnitFamily`.
+///
+/// # Safety
+/// `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn space_rpg_unit_from_id(
+    family: u32,
+    id: u32,
+    out: *mut SpaceRpgUnit,
+) -> SpaceRpgStatus {
+    let out = try_status!(out.as_mut(), SpaceRpgStatus::NullPointer);
+    let unit = SpaceRpgUnit { family, id };
+    try_status!(unit.to_standard(), SpaceRpgStatus::InvalidUnitId);
+    *out = unit;
+    SpaceRpgStatus::Ok
+}
+
+/// # Safety
+/// `symbol` must be null or a NUL terminated UTF-8 string, `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn space_rpg_unit_from_symbol(
+    symbol: *const c_char,
+    out: *mut SpaceRpgUnit,
+) -> SpaceRpgStatus {
+    if symbol.is_null() {
+        return SpaceRpgStatus::NullPointer;
+    }
+    let out = try_status!(out.as_mut(), SpaceRpgStatus::NullPointer);
+    let symbol = try_status!(CStr::from_ptr(symbol).to_str().ok(), SpaceRpgStatus::UnknownSymbol);
+    let unit = try_status!(StandardUnit::from_symbol(symbol), SpaceRpgStatus::UnknownSymbol);
+    *out = unit.into();
+    SpaceRpgStatus::Ok
+}
+
+/// Writes the NUL terminated UTF-8 symbol of `unit` into `buffer` of `length` bytes.
+///
+/// # Safety
+/// `buffer` must be null or valid for `length` bytes of writes.
+#[no_mangle]
+pub unsafe extern "C" fn space_rpg_unit_symbol(
+    unit: SpaceRpgUnit,
+    buffer: *mut c_char,
+    length: usize,
+) -> SpaceRpgStatus {
+    if buffer.is_null() {
+        return SpaceRpgStatus::NullPointer;
+    }
+    let symbol = try_status!(unit.to_standard(), SpaceRpgStatus::InvalidUnitId).symbol();
+    if symbol.len() >= length {
+        return SpaceRpgStatus::BufferTooSmall;
+    }
+    std::ptr::copy_nonoverlapping(symbol.as_ptr() as *const c_char, buffer, symbol.len());
+    *buffer.add(symbol.len()) = 0;
+    SpaceRpgStatus::Ok
+}
+
+/// # Safety
+/// `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn space_rpg_unit_dims(
+    unit: SpaceRpgUnit,
+    out: *mut SpaceRpgDims,
+) -> SpaceRpgStatus {
+    let out = try_status!(out.as_mut(), SpaceRpgStatus::NullPointer);
+    let unit = try_status!(unit.to_standard(), SpaceRpgStatus::InvalidUnitId);
+    *out = unit.dims().into();
+    SpaceRpgStatus::Ok
+}
+
+/// Converts `value` in `from` into `to`.
+///
+/// # Safety
+/// `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn space_rpg_convert(
+    value: f64,
+    from: SpaceRpgUnit,
+    to: SpaceRpgUnit,
+    out: *mut f64,
+) -> SpaceRpgStatus {
+    let out = try_status!(out.as_mut(), SpaceRpgStatus::NullPointer);
+    let from = try_status!(from.to_standard(), SpaceRpgStatus::InvalidUnitId);
+    let to = try_status!(to.to_standard(), SpaceRpgStatus::InvalidUnitId);
+    if from.dims() != to.dims() {
+        return SpaceRpgStatus::DimensionMismatch;
+    }
+    // Only the reduced ratio is rounded, the unit amounts themselves can be far apart.
+    let ratio = from.exact_amount_of_base() / to.exact_amount_of_base();
+    *out = value * f64::from_exact(&ratio);
+    SpaceRpgStatus::Ok
+}
+
+/// Parses a quantity such as `12.5 km` into its value and unit.
+///
+/// # Safety
+/// `text` must be null or a NUL terminated UTF-8 string, `value` and `unit` must be null or
+/// valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn space_rpg_parse_quantity(
+    text: *const c_char,
+    value: *mut f64,
+    unit: *mut SpaceRpgUnit,
+) -> SpaceRpgStatus {
+    if text.is_null() || value.is_null() || unit.is_null() {
+        return SpaceRpgStatus::NullPointer;
+    }
+    let text = try_status!(CStr::from_ptr(text).to_str().ok(), SpaceRpgStatus::ParseError);
+    let mut parts = text.split_whitespace();
+    let (number, symbol) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(number), Some(symbol), None) => (number, symbol),
+        _ => return SpaceRpgStatus::ParseError,
+    };
+    let number = try_status!(number.parse::<f64>().ok(), SpaceRpgStatus::ParseError);
+    let standard = try_status!(StandardUnit::from_symbol(symbol), SpaceRpgStatus::UnknownSymbol);
+    *value = number;
+    *unit = standard.into();
+    SpaceRpgStatus::Ok
+}
+
+/// The dims of a product, [`SpaceRpgStatus::Overflow`] if an exponent overflows.
+///
+/// # Safety
+/// `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn space_rpg_dims_mul(
+    lhs: SpaceRpgDims,
+    rhs: SpaceRpgDims,
+    out: *mut SpaceRpgDims,
+) -> SpaceRpgStatus {
+    let out = try_status!(out.as_mut(), SpaceRpgStatus::NullPointer);
+    *out = try_status!(lhs.checked_zip(rhs, isize::checked_add), SpaceRpgStatus::Overflow);
+    SpaceRpgStatus::Ok
+}
+
+/// The dims of a quotient, [`SpaceRpgStatus::Overflow`] if an exponent overflows.
+///
+/// # Safety
+/// `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn space_rpg_dims_div(
+    lhs: SpaceRpgDims,
+    rhs: SpaceRpgDims,
+    out: *mut SpaceRpgDims,
+) -> SpaceRpgStatus {
+    let out = try_status!(out.as_mut(), SpaceRpgStatus::NullPointer);
+    *out = try_status!(lhs.checked_zip(rhs, isize::checked_sub), SpaceRpgStatus::Overflow);
+    SpaceRpgStatus::Ok
+}
+
+/// The dims raised to `exponent`, [`SpaceRpgStatus::Overflow`] if an exponent overflows.
+///
+/// # Safety
+/// `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn space_rpg_dims_pow(
+    dims: SpaceRpgDims,
+    exponent: isize,
+    out: *mut SpaceRpgDims,
+) -> SpaceRpgStatus {
+    let out = try_status!(out.as_mut(), SpaceRpgStatus::NullPointer);
+    let exponents = SpaceRpgDims {
+        time: exponent,
+        length: exponent,
+        mass: exponent,
+        electric_current: exponent,
+        temperature: exponent,
+        amount: exponent,
+        luminous_intensity: exponent,
+    };
+    *out = try_status!(dims.checked_zip(exponents, isize::checked_mul), SpaceRpgStatus::Overflow);
+    SpaceRpgStatus::Ok
+}
+
+/// Returns [`SpaceRpgStatus::DimensionMismatch`] unless `lhs` and `rhs` are equal.
+#[no_mangle]
+pub extern "C" fn space_rpg_dims_check(lhs: SpaceRpgDims, rhs: SpaceRpgDims) -> SpaceRpgStatus {
+    if lhs == rhs {
+        SpaceRpgStatus::Ok
+    } else {
+        SpaceRpgStatus::DimensionMismatch
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::ffi::CString;
+
+    use crate::*;
+
+    #[test]
+    fn convert_test() {
+        let mut kilometer = SpaceRpgUnit::from(StandardUnit::Length(LengthUnit::Meter));
+        let symbol = CString::new("km").unwrap();
+        unsafe {
+            assert_eq!(
+                SpaceRpgStatus::Ok,
+                space_rpg_unit_from_symbol(symbol.as_ptr(), &mut kilometer)
+            );
+        }
+        assert_eq!(SpaceRpgUnitFamily::Length as u32, kilometer.family);
+
+        let meter = SpaceRpgUnit::from(StandardUnit::Length(LengthUnit::Meter));
+        let mut out = 0.0;
+        unsafe {
+            assert_eq!(
+                SpaceRpgStatus::Ok,
+                space_rpg_convert(2.5, kilometer, meter, &mut out)
+            );
+        }
+        assert_eq!(2500.0, out);
+
+        let second = SpaceRpgUnit::from(StandardUnit::Time(TimeUnit::Second));
+        unsafe {
+            assert_eq!(
+                SpaceRpgStatus::DimensionMismatch,
+                space_rpg_convert(1.0, meter, second, &mut out)
+            );
+        }
+        assert_eq!(2500.0, out);
+    }
+
+    #[test]
+    fn id_test() {
+        for unit in StandardUnit::all() {
+            let id = SpaceRpgUnit::from(unit);
+            assert_eq!(Some(unit), id.to_standard());
+        }
+        let family = SpaceRpgUnitFamily::Time as u32;
+        let invalid = SpaceRpgUnit {
+            family,
+            id: space_rpg_unit_count(family) as u32,
+        };
+        assert_eq!(None, invalid.to_standard());
+        let bogus = SpaceRpgUnit {
+            family: 42,
+            id: 0,
+        };
+        assert_eq!(None, bogus.to_standard());
+        assert_eq!(0, space_rpg_unit_count(42));
+
+        for (index, family) in SpaceRpgUnitFamily::ALL.iter().enumerate() {
+            assert_eq!(index as u32, *family as u32);
+        }
+        for (index, status) in SpaceRpgStatus::ALL.iter().enumerate() {
+            assert_eq!(index as u32, *status as u32);
+        }
+
+        // Ids are fixed whatever the order of the family.
+        assert_eq!(
+            SpaceRpgUnit { family, id: 3 },
+            SpaceRpgUnit::from(StandardUnit::Time(TimeUnit::Second))
+        );
+        assert_eq!(
+            SpaceRpgUnit {
+                family: SpaceRpgUnitFamily::Length as u32,
+                id: 7,
+            },
+            SpaceRpgUnit::from(StandardUnit::Length(LengthUnit::AU))
+        );
+    }
+
+    #[test]
+    fn dims_test() {
+        let length = SpaceRpgDims::from(UnitDims::LENGTH);
+        let time = SpaceRpgDims::from(UnitDims::TIME);
+        let mut out = length;
+        unsafe {
+            assert_eq!(SpaceRpgStatus::Ok, space_rpg_dims_div(length, time, &mut out));
+            assert_eq!(SpaceRpgDims::from(UnitDims::LENGTH.sub(UnitDims::TIME)), out);
+            assert_eq!(SpaceRpgStatus::Ok, space_rpg_dims_pow(length, 3, &mut out));
+            assert_eq!(SpaceRpgDims::from(UnitDims::LENGTH.mul(3)), out);
+
+            let huge = SpaceRpgDims::from(UnitDims::LENGTH.mul(isize::MAX));
+            assert_eq!(SpaceRpgStatus::Overflow, space_rpg_dims_pow(huge, 2, &mut out));
+            assert_eq!(SpaceRpgStatus::Overflow, space_rpg_dims_mul(huge, length, &mut out));
+            assert_eq!(SpaceRpgDims::from(UnitDims::LENGTH.mul(3)), out);
+            assert_eq!(
+                SpaceRpgStatus::NullPointer,
+                space_rpg_dims_mul(length, time, std::ptr::null_mut())
+            );
+        }
+    }
+}
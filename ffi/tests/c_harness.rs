@@ -0,0 +1,60 @@
+// Links the f128 backend's libquadmath and the glibc system libraries.
+#![cfg(all(feature = "f128", target_os = "linux"))]
+
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
+// Builds tests/harness.c against the static library and the generated header, cargo only builds
+// the rlib for tests so the static library is built here, with the same profile and into the
+// same target directory as this test.
+#[test]
+fn c_harness_test() {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    // The test runs from `<target dir>/<profile>/deps`.
+    let profile_dir = env::current_exe()
+        .unwrap()
+        .parent()
+        .and_then(|deps| deps.parent())
+        .unwrap()
+        .to_path_buf();
+    let target_dir = profile_dir.parent().unwrap();
+    let profile = profile_dir.file_name().unwrap().to_str().unwrap();
+
+    let mut build = Command::new(env!("CARGO"));
+    build
+        .args(["build", "--package", "space_rpg_ffi", "--target-dir"])
+        .arg(target_dir)
+        .current_dir(&manifest_dir);
+    match profile {
+        "debug" => {}
+        "release" => {
+            build.arg("--release");
+        }
+        profile => {
+            build.args(["--profile", profile]);
+        }
+    }
+    let status = build.status().unwrap();
+    assert!(status.success(), "failed to build space_rpg_ffi");
+
+    let harness = profile_dir.join("space_rpg_ffi_harness");
+    let status = Command::new(env::var("CC").unwrap_or_else(|_| "cc".to_string()))
+        .arg(manifest_dir.join("tests/harness.c"))
+        .arg("-I")
+        .arg(env!("OUT_DIR"))
+        .arg(profile_dir.join("libspace_rpg_ffi.a"))
+        .args(["-lquadmath", "-lpthread", "-ldl", "-lm", "-o"])
+        .arg(&harness)
+        .status()
+        .unwrap();
+    assert!(status.success(), "failed to compile harness.c");
+
+    let output = Command::new(&harness).output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!("ok\n", String::from_utf8_lossy(&output.stdout));
+}
@@ -0,0 +1,66 @@
+#include <math.h>
+#include <stdint.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "space_rpg.h"
+
+#define CHECK(condition)                                              \
+    do {                                                              \
+        if (!(condition)) {                                           \
+            fprintf(stderr, "%s:%d: %s\n", __FILE__, __LINE__, #condition); \
+            return 1;                                                 \
+        }                                                             \
+    } while (0)
+
+int main(void) {
+    SpaceRpgUnit kilometer;
+    SpaceRpgUnit au;
+    SpaceRpgUnit second;
+    CHECK(space_rpg_unit_from_symbol("km", &kilometer) == SPACE_RPG_STATUS_OK);
+    CHECK(space_rpg_unit_from_symbol("au", &au) == SPACE_RPG_STATUS_OK);
+    CHECK(space_rpg_unit_from_id(SPACE_RPG_UNIT_FAMILY_TIME, 3, &second) == SPACE_RPG_STATUS_OK);
+    CHECK(space_rpg_unit_from_symbol("parsec", &au) == SPACE_RPG_STATUS_UNKNOWN_SYMBOL);
+    CHECK(space_rpg_unit_from_id(SPACE_RPG_UNIT_FAMILY_TIME, 1000, &second) == SPACE_RPG_STATUS_INVALID_UNIT_ID);
+    CHECK(space_rpg_unit_from_id(1000, 0, &second) == SPACE_RPG_STATUS_INVALID_UNIT_ID);
+    CHECK(space_rpg_unit_count(1000) == 0);
+    SpaceRpgUnit bogus = {1000, 0};
+    CHECK(space_rpg_unit_dims(bogus, &(SpaceRpgDims){0}) == SPACE_RPG_STATUS_INVALID_UNIT_ID);
+
+    char symbol[8];
+    CHECK(space_rpg_unit_symbol(second, symbol, sizeof(symbol)) == SPACE_RPG_STATUS_OK);
+    CHECK(strcmp(symbol, "s") == 0);
+    CHECK(space_rpg_unit_symbol(kilometer, symbol, 2) == SPACE_RPG_STATUS_BUFFER_TOO_SMALL);
+
+    double value;
+    CHECK(space_rpg_convert(149597870.7, kilometer, au, &value) == SPACE_RPG_STATUS_OK);
+    CHECK(fabs(value - 1.0) < 1e-12);
+    CHECK(space_rpg_convert(1.0, kilometer, second, &value) == SPACE_RPG_STATUS_DIMENSION_MISMATCH);
+    CHECK(strcmp(space_rpg_status_message(SPACE_RPG_STATUS_DIMENSION_MISMATCH), "dimension mismatch") == 0);
+    CHECK(strcmp(space_rpg_status_message(1000), "unknown status") == 0);
+
+    SpaceRpgUnit parsed;
+    CHECK(space_rpg_parse_quantity("12.5 km", &value, &parsed) == SPACE_RPG_STATUS_OK);
+    CHECK(value == 12.5);
+    CHECK(parsed.family == kilometer.family && parsed.id == kilometer.id);
+    CHECK(space_rpg_parse_quantity("twelve km", &value, &parsed) == SPACE_RPG_STATUS_PARSE_ERROR);
+
+    SpaceRpgDims length;
+    SpaceRpgDims time;
+    CHECK(space_rpg_unit_dims(kilometer, &length) == SPACE_RPG_STATUS_OK);
+    CHECK(space_rpg_unit_dims(second, &time) == SPACE_RPG_STATUS_OK);
+    SpaceRpgDims velocity;
+    CHECK(space_rpg_dims_div(length, time, &velocity) == SPACE_RPG_STATUS_OK);
+    CHECK(velocity.length == 1 && velocity.time == -1);
+    SpaceRpgDims distance;
+    CHECK(space_rpg_dims_mul(velocity, time, &distance) == SPACE_RPG_STATUS_OK);
+    CHECK(space_rpg_dims_check(distance, length) == SPACE_RPG_STATUS_OK);
+    SpaceRpgDims area;
+    CHECK(space_rpg_dims_pow(length, 2, &area) == SPACE_RPG_STATUS_OK);
+    CHECK(space_rpg_dims_check(area, length) == SPACE_RPG_STATUS_DIMENSION_MISMATCH);
+    CHECK(space_rpg_dims_pow(length, PTRDIFF_MAX, &area) == SPACE_RPG_STATUS_OK);
+    CHECK(space_rpg_dims_pow(area, 2, &area) == SPACE_RPG_STATUS_OVERFLOW);
+
+    printf("ok\n");
+    return 0;
+}
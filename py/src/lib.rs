@@ -0,0 +1,478 @@
+// Raised by code that the pyo3 0.18 macros expand to on current nightlies.
+#![allow(non_local_definitions, unexpected_cfgs)]
+//! Python bindings over [`space_rpg::units`], built as the `space_rpg_py` extension module with
+//! maturin.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use pyo3::basic::CompareOp;
+use pyo3::create_exception;
+use pyo3::exceptions::{PyKeyError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use space_rpg::units::*;
+
+create_exception!(space_rpg_py, DimensionError, PyValueError);
+
+fn hash_of(value: impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn compare<T: PartialOrd>(lhs: &T, rhs: &T, op: CompareOp) -> bool {
+    match op {
+        CompareOp::Lt => lhs < rhs,
+        CompareOp::Le => lhs <= rhs,
+        CompareOp::Eq => lhs == rhs,
+        CompareOp::Ne => lhs != rhs,
+        CompareOp::Gt => lhs > rhs,
+        CompareOp::Ge => lhs >= rhs,
+    }
+}
+
+fn dimension_error(lhs: UnitDims, rhs: UnitDims) -> PyErr {
+    DimensionError::new_err(format!("dimension mismatch: [{}] and [{}]", lhs, rhs))
+}
+
+#[pyclass(name = "UnitDims")]
+#[derive(Copy, Clone, Debug)]
+struct PyUnitDims(UnitDims);
+#[pymethods]
+impl PyUnitDims {
+    #[new]
+    #[pyo3(signature = (
+        time = 0,
+        length = 0,
+        mass = 0,
+        electric_current = 0,
+        temperature = 0,
+        amount = 0,
+        luminous_intensity = 0
+    ))]
+    fn new(
+        time: DimsType,
+        length: DimsType,
+        mass: DimsType,
+        electric_current: DimsType,
+        temperature: DimsType,
+        amount: DimsType,
+        luminous_intensity: DimsType,
+    ) -> Self {
+        Self(UnitDims {
+            time,
+            length,
+            mass,
+            electric_current,
+            temperature,
+            amount,
+            luminous_intensity,
+        })
+    }
+
+    #[getter]
+    fn time(&self) -> DimsType {
+        self.0.time
+    }
+
+    #[getter]
+    fn length(&self) -> DimsType {
+        self.0.length
+    }
+
+    #[getter]
+    fn mass(&self) -> DimsType {
+        self.0.mass
+    }
+
+    #[getter]
+    fn electric_current(&self) -> DimsType {
+        self.0.electric_current
+    }
+
+    #[getter]
+    fn temperature(&self) -> DimsType {
+        self.0.temperature
+    }
+
+    #[getter]
+    fn amount(&self) -> DimsType {
+        self.0.amount
+    }
+
+    #[getter]
+    fn luminous_intensity(&self) -> DimsType {
+        self.0.luminous_intensity
+    }
+
+    fn __mul__(&self, other: PyUnitDims) -> Self {
+        Self(self.0.add(other.0))
+    }
+
+    fn __truediv__(&self, other: PyUnitDims) -> Self {
+        Self(self.0.sub(other.0))
+    }
+
+    fn __pow__(&self, exponent: DimsType, _modulo: Option<DimsType>) -> Self {
+        Self(self.0.mul(exponent))
+    }
+
+    fn __richcmp__(&self, other: PyUnitDims, op: CompareOp) -> PyObject {
+        Python::with_gil(|py| match op {
+            CompareOp::Eq => (self.0 == other.0).into_py(py),
+            CompareOp::Ne => (self.0 != other.0).into_py(py),
+            _ => py.NotImplemented(),
+        })
+    }
+
+    fn __hash__(&self) -> u64 {
+        hash_of(self.0)
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("UnitDims({:?})", self.0.to_string())
+    }
+}
+
+#[pyclass(name = "Unit")]
+#[derive(Copy, Clone, Debug)]
+struct PyUnit(StandardUnit);
+#[pymethods]
+impl PyUnit {
+    #[staticmethod]
+    fn from_symbol(symbol: &str) -> PyResult<Self> {
+        StandardUnit::from_symbol(symbol)
+            .map(Self)
+            .ok_or_else(|| PyKeyError::new_err(format!("unknown unit symbol `{}`", symbol)))
+    }
+
+    #[getter]
+    fn symbol(&self) -> &'static str {
+        self.0.symbol()
+    }
+
+    #[getter]
+    fn family(&self) -> &'static str {
+        self.0.family()
+    }
+
+    #[getter]
+    fn dims(&self) -> PyUnitDims {
+        PyUnitDims(self.0.dims())
+    }
+
+    /// The exact size of the unit in base units as `(numerator, denominator)`.
+    #[getter]
+    fn factor(&self) -> (u128, u128) {
+        let factor = self.0.amount_of_base();
+        (factor.numerator(), factor.denominator())
+    }
+
+    #[getter]
+    fn amount_of_base(&self) -> f64 {
+        DisplayNumber::from_factor(self.0.amount_of_base())
+    }
+
+    fn __rmul__(&self, value: f64) -> PyQuantity {
+        PyQuantity(Quantity::from_unit(value, self.0))
+    }
+
+    fn __richcmp__(&self, other: PyUnit, op: CompareOp) -> PyObject {
+        Python::with_gil(|py| match op {
+            CompareOp::Eq => (self.0 == other.0).into_py(py),
+            CompareOp::Ne => (self.0 != other.0).into_py(py),
+            _ => py.NotImplemented(),
+        })
+    }
+
+    fn __hash__(&self) -> u64 {
+        hash_of(self.0)
+    }
+
+    fn __str__(&self) -> &'static str {
+        self.0.symbol()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Unit({:?})", self.0.symbol())
+    }
+}
+
+#[derive(FromPyObject)]
+enum UnitArg {
+    Unit(PyUnit),
+    Symbol(String),
+}
+impl UnitArg {
+    fn into_unit(self) -> PyResult<StandardUnit> {
+        match self {
+            UnitArg::Unit(unit) => Ok(unit.0),
+            UnitArg::Symbol(symbol) => PyUnit::from_symbol(&symbol).map(|unit| unit.0),
+        }
+    }
+}
+
+#[derive(FromPyObject)]
+enum Operand {
+    Quantity(PyQuantity),
+    Number(f64),
+}
+impl Operand {
+    fn into_quantity(self) -> Quantity {
+        match self {
+            Operand::Quantity(quantity) => quantity.0,
+            Operand::Number(number) => Quantity::dimensionless(number),
+        }
+    }
+}
+
+#[pyclass(name = "Quantity")]
+#[derive(Copy, Clone, Debug)]
+struct PyQuantity(Quantity);
+impl PyQuantity {
+    fn checked(
+        &self,
+        other: Quantity,
+        op: fn(Quantity, Quantity) -> Option<Quantity>,
+    ) -> PyResult<Self> {
+        op(self.0, other)
+            .map(Self)
+            .ok_or_else(|| dimension_error(self.0.dims(), other.dims()))
+    }
+}
+#[pymethods]
+impl PyQuantity {
+    #[new]
+    fn new(value: f64, unit: UnitArg) -> PyResult<Self> {
+        Ok(Self(Quantity::from_unit(value, unit.into_unit()?)))
+    }
+
+    /// The value in base units.
+    #[getter]
+    fn value(&self) -> f64 {
+        self.0.value()
+    }
+
+    #[getter]
+    fn dims(&self) -> PyUnitDims {
+        PyUnitDims(self.0.dims())
+    }
+
+    fn to(&self, unit: UnitArg) -> PyResult<f64> {
+        let unit = unit.into_unit()?;
+        self.0
+            .in_unit(unit)
+            .ok_or_else(|| dimension_error(self.0.dims(), unit.dims()))
+    }
+
+    #[pyo3(signature = (unit, precision = None))]
+    fn format(&self, unit: UnitArg, precision: Option<usize>) -> PyResult<String> {
+        let unit = unit.into_unit()?;
+        let value = self.to(UnitArg::Unit(PyUnit(unit)))?;
+        Ok(match precision {
+            Some(precision) => format!("{:.*} {}", precision, value, unit.symbol()),
+            None => format!("{} {}", value, unit.symbol()),
+        })
+    }
+
+    fn __add__(&self, other: Operand) -> PyResult<Self> {
+        self.checked(other.into_quantity(), Quantity::checked_add)
+    }
+
+    fn __radd__(&self, other: Operand) -> PyResult<Self> {
+        // `sum()` starts from the integer 0.
+        if let Operand::Number(number) = other {
+            if number == 0.0 {
+                return Ok(*self);
+            }
+        }
+        self.__add__(other)
+    }
+
+    fn __sub__(&self, other: Operand) -> PyResult<Self> {
+        self.checked(other.into_quantity(), Quantity::checked_sub)
+    }
+
+    fn __rsub__(&self, other: Operand) -> PyResult<Self> {
+        Ok(Self(-self.__sub__(other)?.0))
+    }
+
+    fn __mul__(&self, other: Operand) -> Self {
+        Self(self.0 * other.into_quantity())
+    }
+
+    fn __rmul__(&self, other: Operand) -> Self {
+        Self(other.into_quantity() * self.0)
+    }
+
+    fn __truediv__(&self, other: Operand) -> Self {
+        Self(self.0 / other.into_quantity())
+    }
+
+    fn __rtruediv__(&self, other: Operand) -> Self {
+        Self(other.into_quantity() / self.0)
+    }
+
+    fn __pow__(&self, exponent: i32, _modulo: Option<i32>) -> Self {
+        Self(self.0.powi(exponent))
+    }
+
+    fn __neg__(&self) -> Self {
+        Self(-self.0)
+    }
+
+    fn __float__(&self) -> PyResult<f64> {
+        let dims = self.0.dims();
+        if dims == UnitDims::DIMENSIONLESS {
+            Ok(self.0.value())
+        } else {
+            Err(dimension_error(dims, UnitDims::DIMENSIONLESS))
+        }
+    }
+
+    /// Quantities of different dims are never equal but can't be ordered, anything that isn't a
+    /// quantity is left to Python.
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        let py = other.py();
+        let other = match other.extract::<PyQuantity>() {
+            Ok(other) => other.0,
+            Err(_) => return Ok(py.NotImplemented()),
+        };
+        if self.0.dims() != other.dims() {
+            return match op {
+                CompareOp::Eq => Ok(false.into_py(py)),
+                CompareOp::Ne => Ok(true.into_py(py)),
+                _ => Err(dimension_error(self.0.dims(), other.dims())),
+            };
+        }
+        Ok(compare(&self.0.value(), &other.value(), op).into_py(py))
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Quantity({})", self.0)
+    }
+}
+
+#[pyfunction]
+fn unit(symbol: &str) -> PyResult<PyUnit> {
+    PyUnit::from_symbol(symbol)
+}
+
+#[pyfunction]
+fn units() -> Vec<PyUnit> {
+    StandardUnit::all().map(PyUnit).collect()
+}
+
+/// The standard unit families keyed by name, each list starts with the family's base unit.
+#[pyfunction]
+fn families(py: Python<'_>) -> PyResult<&PyDict> {
+    let mut families: Vec<(&'static str, Vec<PyUnit>)> = Vec::new();
+    for unit in StandardUnit::all() {
+        let family = unit.family();
+        match families.iter_mut().find(|(name, _)| *name == family) {
+            Some((_, members)) => members.push(PyUnit(unit)),
+            None => families.push((family, vec![PyUnit(unit)])),
+        }
+    }
+
+    let out = PyDict::new(py);
+    for (family, members) in families {
+        out.set_item(family, members.into_py(py))?;
+    }
+    Ok(out)
+}
+
+#[pymodule]
+fn space_rpg_py(py: Python<'_>, module: &PyModule) -> PyResult<()> {
+    module.add_class::<PyUnitDims>()?;
+    module.add_class::<PyUnit>()?;
+    module.add_class::<PyQuantity>()?;
+    module.add("DimensionError", py.get_type::<DimensionError>())?;
+    module.add_function(wrap_pyfunction!(unit, module)?)?;
+    module.add_function(wrap_pyfunction!(units, module)?)?;
+    module.add_function(wrap_pyfunction!(families, module)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use pyo3::prelude::*;
+    use pyo3::py_run;
+
+    use crate::space_rpg_py;
+
+    fn run(code: &str) {
+        Python::with_gil(|py| {
+            let module = PyModule::new(py, "space_rpg_py").unwrap();
+            space_rpg_py(py, module).unwrap();
+            py_run!(py, module, code);
+        });
+    }
+
+    #[test]
+    fn unit_test() {
+        run(r#"
+km = module.unit("km")
+assert km.family == "length"
+assert km.factor == (1_000_000_000_000, 1)
+assert module.Unit.from_symbol("oz").factor == (56699, 2)
+assert km.dims == module.UnitDims(length=1)
+assert [unit.symbol for unit in module.families()["time"]][:2] == ["ns", "µs"]
+try:
+    module.unit("parsec")
+    assert False
+except KeyError:
+    pass
+"#);
+    }
+
+    #[test]
+    fn quantity_test() {
+        run(r#"
+distance = module.Quantity(3, "km")
+speed = distance / module.Quantity(2, "min")
+assert speed.dims == module.UnitDims(length=1, time=-1)
+assert (distance + 500 * module.unit("m")).to("m") == 3500
+assert (distance * 2).format("km", 1) == "6.0 km"
+assert str(module.Quantity(1.5, "nm") / module.Quantity(1, "ns")) == "1.5 nm ns^-1"
+assert (distance ** 2).dims == module.UnitDims(length=2)
+assert distance > module.Quantity(2, "km")
+assert distance == module.Quantity(3000, "m")
+assert distance != module.Quantity(3, "s")
+assert not (distance == module.Quantity(3, "s"))
+assert distance != "3 km"
+assert distance not in [None, 3, module.Quantity(3, "s")]
+assert sum([distance, module.Quantity(500, "m")]).to("m") == 3500
+try:
+    distance < module.Quantity(1, "s")
+    assert False
+except module.DimensionError:
+    pass
+try:
+    distance < 3
+    assert False
+except TypeError:
+    pass
+try:
+    distance + module.Quantity(1, "s")
+    assert False
+except module.DimensionError:
+    pass
+try:
+    speed.to("m")
+    assert False
+except ValueError:
+    pass
+"#);
+    }
+}
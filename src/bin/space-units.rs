@@ -0,0 +1,113 @@
+use std::env;
+use std::path::PathBuf;
+use std::process;
+
+use rustyline::error::ReadlineError;
+use rustyline::Editor;
+
+use space_rpg::units::{Answer, Calculator, TableFormat, UnitRegistry};
+
+const HELP: &str = "\
+Enter an expression such as `3 AU / c in Minute` or `(2 EarthMass) * G / (6371 km)^2`.
+  name = expr    store a variable, the last answer is always `ans`
+  expr in unit   show the answer in `unit`, e.g. `in km/s`
+  vars           list variables
+  help           show this message
+  exit           quit
+
+Run `space-units export [json|csv|markdown]` to print the conversion table.";
+
+fn history_path() -> Option<PathBuf> {
+    env::var_os("HOME").map(|home| PathBuf::from(home).join(".space-units-history"))
+}
+
+fn run_line(calculator: &mut Calculator, line: &str) -> bool {
+    match calculator.evaluate(line) {
+        Ok(answer) => {
+            println!("{}", answer);
+            true
+        }
+        Err(error) => {
+            eprintln!("error: {}", error);
+            false
+        }
+    }
+}
+
+fn repl(calculator: &mut Calculator) {
+    let mut editor = Editor::<()>::new();
+    let history = history_path();
+    if let Some(history) = &history {
+        // A missing history file just means this is the first run.
+        let _ = editor.load_history(history);
+    }
+
+    loop {
+        let line = match editor.readline("> ") {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) | Err(ReadlineError::Eof) => break,
+            Err(error) => {
+                eprintln!("error: {}", error);
+                break;
+            }
+        };
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        editor.add_history_entry(line);
+
+        match line {
+            "exit" | "quit" => break,
+            "help" => println!("{}", HELP),
+            "vars" => {
+                for (name, value) in calculator.variables() {
+                    let answer = Answer {
+                        name: Some(name.to_string()),
+                        quantity: *value,
+                        unit: None,
+                    };
+                    println!("{}", answer);
+                }
+            }
+            line => {
+                run_line(calculator, line);
+            }
+        }
+    }
+
+    if let Some(history) = &history {
+        if let Err(error) = editor.save_history(history) {
+            eprintln!("error: could not save history: {}", error);
+        }
+    }
+}
+
+fn export(format: Option<&str>) -> bool {
+    match format.unwrap_or("markdown").parse::<TableFormat>() {
+        Ok(format) => {
+            print!("{}", UnitRegistry::new().export(format));
+            true
+        }
+        Err(error) => {
+            eprintln!("error: {}", error);
+            false
+        }
+    }
+}
+
+fn main() {
+    let mut calculator = Calculator::new();
+    let args: Vec<String> = env::args().skip(1).collect();
+    let success = match args.first().map(String::as_str) {
+        None => {
+            repl(&mut calculator);
+            true
+        }
+        Some("export") if args.len() <= 2 => export(args.get(1).map(String::as_str)),
+        Some(_) => run_line(&mut calculator, &args.join(" ")),
+    };
+    if !success {
+        process::exit(1);
+    }
+}
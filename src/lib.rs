@@ -1,12 +1,12 @@
-#![feature(
-    const_generics,
-    const_evaluatable_checked,
-    const_mut_refs,
-    const_fn_trait_bound,
-    const_panic
-)]
+#![feature(adt_const_params, generic_const_exprs)]
 #![allow(incomplete_features)]
 #![warn(missing_debug_implementations)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+#[cfg(all(feature = "f128", not(feature = "std")))]
+compile_error!("the f128 DisplayValue backend links libquadmath and requires the std feature");
 
 pub mod units;
 
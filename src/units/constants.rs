@@ -0,0 +1,134 @@
+//! Physical constants (CODATA 2018 and IAU nominal values).
+//!
+//! The top level constants are in the crate's base units (nanometre, milligram, nanosecond,
+//! picoampere, kelvin, mole), [`si`] holds the same constants with their values in SI units.
+//! Both are typed by their dimensions.
+
+use crate::units::{
+    Acceleration, Action, Charge, Entropy, InverseAmount, Length, Mass, MolarEntropy, Permeability,
+    Permittivity, UnitDims, UnitValue, Velocity,
+};
+
+const GRAVITATIONAL: UnitDims = UnitDims {
+    length: 3,
+    mass: -1,
+    time: -2,
+    ..UnitDims::DIMENSIONLESS
+};
+const STEFAN_BOLTZMANN: UnitDims = UnitDims {
+    mass: 1,
+    time: -3,
+    temperature: -4,
+    ..UnitDims::DIMENSIONLESS
+};
+
+/// The constants with values in SI units (m, kg, s, A, K, mol) rather than base units, e.g. for
+/// display or for formulas written in SI. Only mix them with other SI values, the top level
+/// constants are the ones to use with the rest of the crate.
+pub mod si {
+    use super::{GRAVITATIONAL, STEFAN_BOLTZMANN};
+    use crate::units::{
+        Acceleration, Action, Charge, Entropy, InverseAmount, Length, Mass, MolarEntropy,
+        Permeability, Permittivity, UnitValue, Velocity,
+    };
+
+    pub const SPEED_OF_LIGHT: Velocity<f64> = UnitValue::new(299_792_458.0);
+    pub const GRAVITATIONAL_CONSTANT: UnitValue<f64, GRAVITATIONAL> = UnitValue::new(6.674_30e-11);
+    pub const PLANCK_CONSTANT: Action<f64> = UnitValue::new(6.626_070_15e-34);
+    pub const REDUCED_PLANCK_CONSTANT: Action<f64> = UnitValue::new(1.054_571_817e-34);
+    pub const BOLTZMANN_CONSTANT: Entropy<f64> = UnitValue::new(1.380_649e-23);
+    pub const AVOGADRO_CONSTANT: InverseAmount<f64> = UnitValue::new(6.022_140_76e23);
+    pub const MOLAR_GAS_CONSTANT: MolarEntropy<f64> = UnitValue::new(8.314_462_618);
+    pub const ELEMENTARY_CHARGE: Charge<f64> = UnitValue::new(1.602_176_634e-19);
+    pub const STEFAN_BOLTZMANN_CONSTANT: UnitValue<f64, STEFAN_BOLTZMANN> =
+        UnitValue::new(5.670_374_419e-8);
+    pub const VACUUM_PERMITTIVITY: Permittivity<f64> = UnitValue::new(8.854_187_812_8e-12);
+    pub const VACUUM_PERMEABILITY: Permeability<f64> = UnitValue::new(1.256_637_062_12e-6);
+    pub const STANDARD_GRAVITY: Acceleration<f64> = UnitValue::new(9.806_65);
+
+    pub const ASTRONOMICAL_UNIT: Length<f64> = UnitValue::new(149_597_870_700.0);
+    pub const LIGHT_YEAR: Length<f64> = UnitValue::new(9_460_730_472_580_800.0);
+    pub const EARTH_MASS: Mass<f64> = UnitValue::new(5.972_2e24);
+    pub const SOLAR_MASS: Mass<f64> = UnitValue::new(1.988_47e30);
+}
+
+// Scale factors from SI to base units: 1 m = 1e9 nm, 1 kg = 1e6 mg, 1 s = 1e9 ns, 1 A = 1e12 pA.
+pub const SPEED_OF_LIGHT: Velocity<f64> = UnitValue::new(*si::SPEED_OF_LIGHT.value());
+pub const GRAVITATIONAL_CONSTANT: UnitValue<f64, GRAVITATIONAL> =
+    UnitValue::new(*si::GRAVITATIONAL_CONSTANT.value() * 1e3);
+pub const PLANCK_CONSTANT: Action<f64> = UnitValue::new(*si::PLANCK_CONSTANT.value() * 1e15);
+pub const REDUCED_PLANCK_CONSTANT: Action<f64> =
+    UnitValue::new(*si::REDUCED_PLANCK_CONSTANT.value() * 1e15);
+pub const BOLTZMANN_CONSTANT: Entropy<f64> = UnitValue::new(*si::BOLTZMANN_CONSTANT.value() * 1e6);
+pub const AVOGADRO_CONSTANT: InverseAmount<f64> = UnitValue::new(*si::AVOGADRO_CONSTANT.value());
+pub const MOLAR_GAS_CONSTANT: MolarEntropy<f64> =
+    UnitValue::new(*si::MOLAR_GAS_CONSTANT.value() * 1e6);
+pub const ELEMENTARY_CHARGE: Charge<f64> = UnitValue::new(*si::ELEMENTARY_CHARGE.value() * 1e21);
+pub const STEFAN_BOLTZMANN_CONSTANT: UnitValue<f64, STEFAN_BOLTZMANN> =
+    UnitValue::new(*si::STEFAN_BOLTZMANN_CONSTANT.value() * 1e-21);
+pub const VACUUM_PERMITTIVITY: Permittivity<f64> =
+    UnitValue::new(*si::VACUUM_PERMITTIVITY.value() * 1e27);
+pub const VACUUM_PERMEABILITY: Permeability<f64> =
+    UnitValue::new(*si::VACUUM_PERMEABILITY.value() * 1e-27);
+pub const STANDARD_GRAVITY: Acceleration<f64> = UnitValue::new(*si::STANDARD_GRAVITY.value() * 1e-9);
+
+pub const ASTRONOMICAL_UNIT: Length<f64> = UnitValue::new(*si::ASTRONOMICAL_UNIT.value() * 1e9);
+pub const LIGHT_YEAR: Length<f64> = UnitValue::new(*si::LIGHT_YEAR.value() * 1e9);
+pub const EARTH_MASS: Mass<f64> = UnitValue::new(*si::EARTH_MASS.value() * 1e6);
+pub const SOLAR_MASS: Mass<f64> = UnitValue::new(*si::SOLAR_MASS.value() * 1e6);
+
+#[cfg(test)]
+mod test {
+    use crate::units::constants::*;
+    use num_traits::ToPrimitive;
+
+    use crate::units::{LengthUnit, MassUnit, StandardUnitImpl, TimeUnit, UnitDims};
+
+    fn assert_close(expected: f64, actual: f64) {
+        assert!(
+            ((expected - actual) / expected).abs() < 1e-12,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn astronomical_units_test() {
+        // The unit families spell out the same literals as `si`, they must stay identical.
+        fn in_si<U, const DIMS: UnitDims>(unit: U, si_unit: U) -> Option<f64>
+        where
+            U: StandardUnitImpl<DIMS>,
+        {
+            (unit.exact_amount_of_base() / si_unit.exact_amount_of_base()).to_f64()
+        }
+        let meter = LengthUnit::Meter;
+        let kilogram = MassUnit::Kilogram;
+        assert_eq!(Some(*si::ASTRONOMICAL_UNIT.value()), in_si(LengthUnit::AU, meter));
+        assert_eq!(Some(*si::LIGHT_YEAR.value()), in_si(LengthUnit::LightYear, meter));
+        assert_eq!(Some(*si::EARTH_MASS.value()), in_si(MassUnit::EarthMass, kilogram));
+        assert_eq!(Some(*si::SOLAR_MASS.value()), in_si(MassUnit::SolarMass, kilogram));
+
+        assert_close(
+            *ASTRONOMICAL_UNIT.value(),
+            LengthUnit::AU.amount_of_base_as(),
+        );
+        assert_close(*SOLAR_MASS.value(), MassUnit::SolarMass.amount_of_base_as());
+    }
+
+    #[test]
+    fn light_year_test() {
+        // A Julian year at the speed of light.
+        let julian_year: f64 = TimeUnit::JulianYear.amount_of_base_as();
+        assert_close(*LIGHT_YEAR.value(), *SPEED_OF_LIGHT.value() * julian_year);
+    }
+
+    #[test]
+    fn standard_gravity_test() {
+        // 1 m/s^2 is 1e9 nm per 1e18 ns^2.
+        assert_close(
+            *si::STANDARD_GRAVITY.value(),
+            *STANDARD_GRAVITY.value() * 1e18 / 1e9,
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use core::fmt::{Debug, Display};
+use core::ops::*;
+
+use num_traits::ToPrimitive;
+
+use crate::units::{ExactValue, Factor};
+
+/// The numeric backend used to view values in display units.
+///
+/// [`DisplayValue`](crate::units::DisplayValue) is picked by cargo feature: `f128` (default) or
+/// `decimal`, which can't be combined, and `f64` when neither is enabled.
+pub trait DisplayNumber:
+    Clone
+    + Debug
+    + Display
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + 'static
+{
+    fn from_exact(value: &ExactValue) -> Self;
+    fn from_factor(factor: Factor) -> Self;
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(&self) -> f64;
+}
+
+impl DisplayNumber for f64 {
+    fn from_exact(value: &ExactValue) -> Self {
+        value.to_f64().unwrap()
+    }
+
+    fn from_factor(factor: Factor) -> Self {
+        factor.numerator() as f64 / factor.denominator() as f64
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn to_f64(&self) -> f64 {
+        *self
+    }
+}
+
+#[cfg(feature = "f128")]
+mod f128_backend {
+    use f128::f128;
+    use num_bigint::BigInt;
+    use num_traits::{Float, Signed, ToPrimitive};
+
+    use crate::units::{DisplayNumber, ExactValue, Factor};
+
+    fn bigint_to_f128(value: &BigInt) -> f128 {
+        // Keep more bits than the f128 mantissa holds and scale the rest back in.
+        let shift = value.bits().saturating_sub(120);
+        let truncated = (value.abs() >> shift).to_i128().unwrap();
+        let mut out = f128::from(truncated) * Float::powi(f128::from(2), shift as i32);
+        if value.is_negative() {
+            out = -out;
+        }
+        out
+    }
+
+    impl DisplayNumber for f128 {
+        fn from_exact(value: &ExactValue) -> Self {
+            bigint_to_f128(value.numer()) / bigint_to_f128(value.denom())
+        }
+
+        fn from_factor(factor: Factor) -> Self {
+            f128::from(factor.numerator()) / f128::from(factor.denominator())
+        }
+
+        fn from_f64(value: f64) -> Self {
+            f128::from(value)
+        }
+
+        fn to_f64(&self) -> f64 {
+            (*self).into()
+        }
+    }
+}
+
+#[cfg(feature = "decimal")]
+mod decimal_backend {
+    use bigdecimal::BigDecimal;
+    use num_traits::{FromPrimitive, ToPrimitive};
+
+    use crate::units::{DisplayNumber, ExactValue, Factor};
+
+    impl DisplayNumber for BigDecimal {
+        fn from_exact(value: &ExactValue) -> Self {
+            BigDecimal::from(value.numer().clone()) / BigDecimal::from(value.denom().clone())
+        }
+
+        fn from_factor(factor: Factor) -> Self {
+            BigDecimal::from(factor.numerator()) / BigDecimal::from(factor.denominator())
+        }
+
+        fn from_f64(value: f64) -> Self {
+            FromPrimitive::from_f64(value).unwrap()
+        }
+
+        fn to_f64(&self) -> f64 {
+            ToPrimitive::to_f64(self).unwrap()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::units::*;
+
+    fn assert_relative_eq<D: DisplayNumber>(expected: &str, actual: D, tolerance: &str) {
+        let expected = D::from_exact(&parse_exact(expected).unwrap());
+        let error = if actual > expected {
+            actual - expected.clone()
+        } else {
+            expected.clone() - actual
+        };
+        assert!(
+            error <= expected.clone() * D::from_exact(&parse_exact(tolerance).unwrap()),
+            "expected {}, got error {}",
+            expected,
+            error
+        );
+    }
+
+    fn conversion_accuracy<D: DisplayNumber>(tolerance: &str) {
+        assert_relative_eq("28349.5", MassUnit::Oz.amount_of_base_as::<D>(), tolerance);
+        assert_relative_eq(
+            "149597870700000000000",
+            LengthUnit::AU.amount_of_base_as::<D>(),
+            tolerance,
+        );
+        assert_relative_eq(
+            "31104000000000000",
+            TimeUnit::GSYear.amount_of_base_as::<D>(),
+            tolerance,
+        );
+
+        // 1 oz in pounds, 28.3495 / 453.59237
+        let base: D = MassUnit::Oz.base_repr_as::<D, D>(D::from_f64(1.0));
+        assert_relative_eq(
+            "0.062499949018101869747059457812308",
+            MassUnit::Pound.display_repr_as::<D, D>(base),
+            tolerance,
+        );
+        // 1 au in light years
+        let base: D = LengthUnit::AU.base_repr_as::<D, D>(D::from_f64(1.0));
+        assert_relative_eq(
+            "0.000015812507409820658475722841790868",
+            LengthUnit::LightYear.display_repr_as::<D, D>(base),
+            tolerance,
+        );
+    }
+
+    #[test]
+    fn f64_backend_test() {
+        conversion_accuracy::<f64>("1e-15");
+    }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn f128_backend_test() {
+        conversion_accuracy::<f128::f128>("1e-30");
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn decimal_backend_test() {
+        conversion_accuracy::<bigdecimal::BigDecimal>("1e-30");
+    }
+
+    #[test]
+    fn display_value_test() {
+        let minute: DisplayValue = TimeUnit::Minute.amount_of_base_as();
+        assert_eq!(minute, DisplayValue::from_f64(60e9));
+    }
+}
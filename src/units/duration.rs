@@ -0,0 +1,134 @@
+use core::convert::TryFrom;
+use core::fmt;
+use core::time::Duration;
+
+use num_traits::ToPrimitive;
+
+use crate::units::{UnitDims, UnitValue, Value};
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+impl From<Duration> for UnitValue<u128, { UnitDims::TIME }> {
+    fn from(from: Duration) -> Self {
+        UnitValue::new(from.as_nanos())
+    }
+}
+impl From<Duration> for UnitValue<f64, { UnitDims::TIME }> {
+    fn from(from: Duration) -> Self {
+        UnitValue::new(from.as_nanos() as f64)
+    }
+}
+macro_rules! try_from_duration {
+    ($($ty:ty),+) => {
+        $(
+            impl TryFrom<Duration> for UnitValue<$ty, { UnitDims::TIME }> {
+                type Error = DurationConversionError;
+
+                fn try_from(from: Duration) -> Result<Self, Self::Error> {
+                    <$ty>::try_from(from.as_nanos())
+                        .map(UnitValue::new)
+                        .map_err(|_| DurationConversionError::OutOfRange)
+                }
+            }
+        )+
+    };
+}
+try_from_duration!(u64, i64, i128);
+
+/// Fails for negative values and values past [`Duration::MAX`], floats are truncated to whole
+/// nanoseconds.
+impl<T> TryFrom<UnitValue<T, { UnitDims::TIME }>> for Duration
+where
+    T: Value + ToPrimitive,
+{
+    type Error = DurationConversionError;
+
+    fn try_from(from: UnitValue<T, { UnitDims::TIME }>) -> Result<Self, Self::Error> {
+        let value = from.value();
+        if value.to_f64().is_some_and(|value| value < 0.0) {
+            return Err(DurationConversionError::Negative);
+        }
+        let nanos = value.to_u128().ok_or(DurationConversionError::OutOfRange)?;
+        let seconds = u64::try_from(nanos / NANOS_PER_SECOND)
+            .map_err(|_| DurationConversionError::OutOfRange)?;
+        Ok(Duration::new(seconds, (nanos % NANOS_PER_SECOND) as u32))
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum DurationConversionError {
+    Negative,
+    OutOfRange,
+}
+impl fmt::Display for DurationConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DurationConversionError::Negative => write!(f, "a duration can't be negative"),
+            DurationConversionError::OutOfRange => write!(f, "duration out of range"),
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for DurationConversionError {}
+
+#[cfg(test)]
+mod test {
+    use core::convert::TryFrom;
+    use core::time::Duration;
+
+    use crate::qty;
+    use crate::units::*;
+
+    #[test]
+    fn from_duration_test() {
+        let frame = Duration::from_micros(16_667);
+        assert_eq!(
+            16_667_000,
+            UnitValue::<u128, { UnitDims::TIME }>::from(frame).into_inner()
+        );
+        assert_eq!(
+            UnitValue::<f64, { UnitDims::TIME }>::new(16_667_000.0),
+            UnitValue::from(frame)
+        );
+        assert_eq!(
+            Ok(16_667_000),
+            GSDuration::try_from(frame).map(UnitValue::into_inner)
+        );
+        assert_eq!(
+            Err(DurationConversionError::OutOfRange),
+            UnitValue::<u64, { UnitDims::TIME }>::try_from(Duration::MAX)
+        );
+    }
+
+    #[test]
+    fn into_duration_test() {
+        assert_eq!(
+            Ok(Duration::from_millis(1500)),
+            Duration::try_from(qty!(1.5 s))
+        );
+        assert_eq!(
+            Ok(Duration::new(90, 1)),
+            Duration::try_from(GSDuration::new(90_000_000_001))
+        );
+        assert_eq!(
+            Err(DurationConversionError::Negative),
+            Duration::try_from(GSDuration::new(-1))
+        );
+        assert_eq!(
+            Err(DurationConversionError::OutOfRange),
+            Duration::try_from(GSDuration::new(i128::MAX))
+        );
+        assert_eq!(
+            Err(DurationConversionError::OutOfRange),
+            Duration::try_from(UnitValue::<f64, { UnitDims::TIME }>::new(f64::NAN))
+        );
+        assert_eq!(
+            UnitError::Negative,
+            UnitError::from(DurationConversionError::Negative)
+        );
+        assert_eq!(
+            UnitError::Overflow,
+            UnitError::from(DurationConversionError::OutOfRange)
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use alloc::string::String;
+use core::fmt;
+use core::ops::Range;
+
+use crate::units::{DurationConversionError, UnitDims};
+
+/// The error returned by the fallible `try_*` unit operations.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum UnitError {
+    DimensionMismatch {
+        expected: UnitDims,
+        found: UnitDims,
+    },
+    UnknownUnit(String),
+    /// The value doesn't fit in the target type.
+    Overflow,
+    /// The value is negative where only non-negative values are allowed.
+    Negative,
+    /// The value can't be represented exactly in the target type.
+    PrecisionLoss,
+    /// `span` is the byte range of the input that failed to parse.
+    Parse {
+        message: &'static str,
+        span: Range<usize>,
+    },
+}
+impl fmt::Display for UnitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnitError::DimensionMismatch { expected, found } => write!(
+                f,
+                "dimension mismatch: expected [{}], found [{}]",
+                expected, found
+            ),
+            UnitError::UnknownUnit(symbol) => write!(f, "unknown unit `{}`", symbol),
+            UnitError::Overflow => write!(f, "value out of range"),
+            UnitError::Negative => write!(f, "value can't be negative"),
+            UnitError::PrecisionLoss => write!(f, "value can't be represented exactly"),
+            UnitError::Parse { message, span } => {
+                write!(f, "{} at {}..{}", message, span.start, span.end)
+            }
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for UnitError {}
+impl From<DurationConversionError> for UnitError {
+    fn from(from: DurationConversionError) -> Self {
+        match from {
+            DurationConversionError::Negative => UnitError::Negative,
+            DurationConversionError::OutOfRange => UnitError::Overflow,
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use num_bigint::BigInt;
+use num_rational::BigRational;
+
+pub type ExactValue = BigRational;
+
+/// Parses a decimal number such as `28.3495`, `-12` or `1.496e+11` into an exact rational.
+pub fn parse_exact(text: &str) -> Option<ExactValue> {
+    let (mantissa, exponent) = match text.find(&['e', 'E'][..]) {
+        Some(index) => (&text[..index], text[index + 1..].parse::<i32>().ok()?),
+        None => (text, 0),
+    };
+    let (negative, mantissa) = match mantissa.strip_prefix('-') {
+        Some(mantissa) => (true, mantissa),
+        None => (false, mantissa.strip_prefix('+').unwrap_or(mantissa)),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if integer.is_empty() && fraction.is_empty()
+        || !integer
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|byte| byte.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let digits: BigInt = [integer, fraction].concat().parse().ok()?;
+    let exponent = exponent - fraction.len() as i32;
+    let ten = BigInt::from(10);
+    let mut value = if exponent >= 0 {
+        ExactValue::from_integer(digits * num_traits::pow(ten, exponent as usize))
+    } else {
+        ExactValue::new(
+            digits,
+            num_traits::pow(ten, exponent.unsigned_abs() as usize),
+        )
+    };
+    if negative {
+        value = -value;
+    }
+    Some(value)
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigInt;
+
+    use crate::units::*;
+
+    fn ratio(numerator: i128, denominator: i128) -> ExactValue {
+        ExactValue::new(BigInt::from(numerator), BigInt::from(denominator))
+    }
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(Some(ratio(56699, 2000)), parse_exact("28.3495"));
+        assert_eq!(Some(ratio(-12, 1)), parse_exact("-12"));
+        assert_eq!(Some(ratio(149_600_000_000, 1)), parse_exact("1.496e+11"));
+        assert_eq!(Some(ratio(1, 1000)), parse_exact("1e-3"));
+        assert_eq!(None, parse_exact("1.2.3"));
+        assert_eq!(None, parse_exact("e5"));
+    }
+
+    #[test]
+    fn exact_factor_test() {
+        assert_eq!(ratio(56699, 2), MassUnit::Oz.exact_amount_of_base());
+        assert_eq!(
+            ratio(149_597_870_700_000_000_000, 1),
+            LengthUnit::AU.exact_amount_of_base()
+        );
+        assert_eq!(
+            ratio(1_000 * 86_400 * 1_000_000_000, 1),
+            TimeUnit::GSMillennium.exact_amount_of_base() / ExactValue::from_integer(360.into())
+        );
+    }
+
+    #[test]
+    fn integer_conversion_test() {
+        let three = ExactValue::from_integer(3.into());
+        let length = UnitValue::<i128, { UnitDims::LENGTH }>::from_standard_unit_exact(
+            &three,
+            LengthUnit::Kilometer,
+        )
+        .unwrap();
+        assert_eq!(3_000_000_000_000, *length.value());
+        assert_eq!(
+            three,
+            length.into_standard_unit_exact(LengthUnit::Kilometer)
+        );
+
+        let ounce = UnitValue::<i128, { UnitDims::MASS }>::from_standard_unit_exact(
+            &ExactValue::from_integer(1.into()),
+            MassUnit::Oz,
+        );
+        assert_eq!(None, ounce);
+        let ounces = UnitValue::<i64, { UnitDims::MASS }>::from_standard_unit_exact(
+            &ExactValue::from_integer(2.into()),
+            MassUnit::Oz,
+        );
+        assert_eq!(Some(56699), ounces.map(UnitValue::into_inner));
+    }
+
+    #[test]
+    fn display_view_test() {
+        assert_eq!(28349.5, MassUnit::Oz.amount_of_base_as::<f64>());
+        assert_eq!(
+            *constants::si::LIGHT_YEAR.value() * 1e9,
+            LengthUnit::LightYear.amount_of_base_as::<f64>()
+        );
+    }
+}
@@ -0,0 +1,610 @@
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::units::constants;
+use crate::units::{
+    DimsType, ElectricCurrentUnit, LengthUnit, MassUnit, Quantity, StandardUnit, StandardUnitImpl,
+    TimeUnit, UnitDims, UnitRegistry,
+};
+
+/// Evaluates unit expressions such as `3 AU / c in Minute` or `x = 2 EarthMass * G`.
+///
+/// Names resolve to variables first, then standard units by symbol or name (`µ` may be written
+/// as `u`), then the constants `c`, `G`, `planck`, `hbar`, `k_B`, `N_A`, `R`, `e`, `sigma`,
+/// `eps0`, `mu0` and `g0`, then prefixed units such as `Gm` or `kA` including the custom units
+/// of its [`UnitRegistry`]. The last answer is kept as `ans`.
+#[derive(Clone, Debug, Default)]
+pub struct Calculator {
+    variables: BTreeMap<String, Quantity>,
+    registry: UnitRegistry,
+}
+impl Calculator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_registry(registry: UnitRegistry) -> Self {
+        Self {
+            variables: BTreeMap::new(),
+            registry,
+        }
+    }
+
+    pub fn registry(&self) -> &UnitRegistry {
+        &self.registry
+    }
+
+    pub fn variables(&self) -> impl Iterator<Item = (&str, &Quantity)> {
+        self.variables
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+    }
+
+    pub fn evaluate(&mut self, line: &str) -> Result<Answer, ExpressionError> {
+        let tokens = tokenize(line)?;
+        let (name, tokens) = match tokens.as_slice() {
+            [Token::Ident(keyword), Token::Ident(name), Token::Equals, rest @ ..]
+                if keyword == "let" =>
+            {
+                (Some(name.clone()), rest)
+            }
+            [Token::Ident(name), Token::Equals, rest @ ..] => (Some(name.clone()), rest),
+            tokens => (None, tokens),
+        };
+
+        let mut parser = Parser {
+            tokens,
+            index: 0,
+            variables: &self.variables,
+            registry: &self.registry,
+        };
+        let quantity = parser.expression()?;
+        let unit = if parser.eat_keyword("in") {
+            let start = parser.index;
+            let unit = parser.expression()?;
+            if unit.dims() != quantity.dims() {
+                return Err(ExpressionError::DimensionMismatch(
+                    quantity.dims(),
+                    unit.dims(),
+                ));
+            }
+            Some((describe(&parser.tokens[start..parser.index]), unit))
+        } else {
+            None
+        };
+        if let Some(token) = parser.tokens.get(parser.index) {
+            return Err(ExpressionError::UnexpectedToken(token.to_string()));
+        }
+
+        if let Some(name) = &name {
+            self.variables.insert(name.clone(), quantity);
+        }
+        self.variables.insert("ans".to_string(), quantity);
+        Ok(Answer {
+            name,
+            quantity,
+            unit,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Answer {
+    pub name: Option<String>,
+    pub quantity: Quantity,
+    /// The text and value of the unit given after `in`.
+    pub unit: Option<(String, Quantity)>,
+}
+/// Writes the answer in the requested unit, or in SI base units when none was given.
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(name) = &self.name {
+            write!(f, "{} = ", name)?;
+        }
+        match &self.unit {
+            Some((text, unit)) => {
+                write_number(f, self.quantity.value() / unit.value())?;
+                write!(f, " {}", text)
+            }
+            None => write_si(f, self.quantity),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExpressionError {
+    UnexpectedToken(String),
+    UnexpectedEnd,
+    UnknownName(String),
+    InvalidNumber(String),
+    DimensionMismatch(UnitDims, UnitDims),
+    ExponentOutOfRange(i32),
+}
+impl fmt::Display for ExpressionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExpressionError::UnexpectedToken(token) => write!(f, "unexpected `{}`", token),
+            ExpressionError::UnexpectedEnd => write!(f, "unexpected end of expression"),
+            ExpressionError::UnknownName(name) => write!(f, "unknown name `{}`", name),
+            ExpressionError::InvalidNumber(number) => write!(f, "invalid number `{}`", number),
+            ExpressionError::DimensionMismatch(lhs, rhs) => {
+                write!(f, "dimension mismatch: [")?;
+                write_si_dims(f, *lhs)?;
+                write!(f, "] and [")?;
+                write_si_dims(f, *rhs)?;
+                write!(f, "]")
+            }
+            ExpressionError::ExponentOutOfRange(exponent) => {
+                write!(f, "exponent {} is out of range", exponent)
+            }
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for ExpressionError {}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Number(String),
+    Ident(String),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Caret,
+    LeftParen,
+    RightParen,
+    Equals,
+}
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Number(text) | Token::Ident(text) => write!(f, "{}", text),
+            Token::Plus => write!(f, "+"),
+            Token::Minus => write!(f, "-"),
+            Token::Star => write!(f, "*"),
+            Token::Slash => write!(f, "/"),
+            Token::Caret => write!(f, "^"),
+            Token::LeftParen => write!(f, "("),
+            Token::RightParen => write!(f, ")"),
+            Token::Equals => write!(f, "="),
+        }
+    }
+}
+
+fn tokenize(line: &str) -> Result<Vec<Token>, ExpressionError> {
+    let mut tokens = Vec::new();
+    let mut chars = line.char_indices().peekable();
+    while let Some((start, char)) = chars.next() {
+        let token = match char {
+            char if char.is_whitespace() => continue,
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' => Token::Star,
+            '/' => Token::Slash,
+            '^' => Token::Caret,
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            '=' => Token::Equals,
+            '0'..='9' | '.' => {
+                let mut end = start + char.len_utf8();
+                let mut exponent = false;
+                while let Some(&(index, next)) = chars.peek() {
+                    let rest = &line[index..];
+                    let is_exponent = !exponent
+                        && (next == 'e' || next == 'E')
+                        && rest[1..]
+                            .trim_start_matches(&['+', '-'][..])
+                            .starts_with(|digit: char| digit.is_ascii_digit());
+                    if is_exponent {
+                        exponent = true;
+                        chars.next();
+                        if let Some(&(_, '+')) | Some(&(_, '-')) = chars.peek() {
+                            chars.next();
+                        }
+                    } else if next.is_ascii_digit() || next == '.' || next == '_' {
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                    end = chars.peek().map_or(line.len(), |&(index, _)| index);
+                }
+                Token::Number(line[start..end].to_string())
+            }
+            char if char.is_alphabetic() || char == '_' => {
+                let mut end = start + char.len_utf8();
+                while let Some(&(index, next)) = chars.peek() {
+                    if next.is_alphanumeric() || next == '_' {
+                        chars.next();
+                        end = index + next.len_utf8();
+                    } else {
+                        break;
+                    }
+                }
+                Token::Ident(line[start..end].to_string())
+            }
+            char => return Err(ExpressionError::UnexpectedToken(char.to_string())),
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+fn describe(tokens: &[Token]) -> String {
+    let mut out = String::new();
+    for (index, token) in tokens.iter().enumerate() {
+        let joined = matches!(token, Token::Caret | Token::RightParen)
+            || matches!(
+                tokens.get(index.wrapping_sub(1)),
+                Some(Token::Caret) | Some(Token::LeftParen)
+            )
+            || matches!(
+                (
+                    tokens.get(index.wrapping_sub(2)),
+                    tokens.get(index.wrapping_sub(1))
+                ),
+                (Some(Token::Caret), Some(Token::Minus))
+            );
+        if index > 0 && !joined {
+            out.push(' ');
+        }
+        out.push_str(&token.to_string());
+    }
+    out
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    index: usize,
+    variables: &'a BTreeMap<String, Quantity>,
+    registry: &'a UnitRegistry,
+}
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&'a Token> {
+        self.tokens.get(self.index)
+    }
+
+    fn next(&mut self) -> Result<&'a Token, ExpressionError> {
+        let token = self.peek().ok_or(ExpressionError::UnexpectedEnd)?;
+        self.index += 1;
+        Ok(token)
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.index += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        match self.peek() {
+            Some(Token::Ident(ident)) if ident == keyword => {
+                self.index += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn expression(&mut self) -> Result<Quantity, ExpressionError> {
+        let mut out = self.term()?;
+        loop {
+            if self.eat(&Token::Plus) {
+                let rhs = self.term()?;
+                out = out
+                    .checked_add(rhs)
+                    .ok_or(ExpressionError::DimensionMismatch(out.dims(), rhs.dims()))?;
+            } else if self.eat(&Token::Minus) {
+                let rhs = self.term()?;
+                out = out
+                    .checked_sub(rhs)
+                    .ok_or(ExpressionError::DimensionMismatch(out.dims(), rhs.dims()))?;
+            } else {
+                return Ok(out);
+            }
+        }
+    }
+
+    /// Juxtaposition multiplies, so `2 EarthMass` is `2 * EarthMass`.
+    fn term(&mut self) -> Result<Quantity, ExpressionError> {
+        let mut out = self.factor()?;
+        loop {
+            match self.peek() {
+                Some(Token::Star) => {
+                    self.index += 1;
+                    out = out * self.factor()?;
+                }
+                Some(Token::Slash) => {
+                    self.index += 1;
+                    out = out / self.factor()?;
+                }
+                Some(Token::Ident(ident)) if ident == "in" => return Ok(out),
+                Some(Token::Number(_)) | Some(Token::Ident(_)) | Some(Token::LeftParen) => {
+                    out = out * self.factor()?;
+                }
+                _ => return Ok(out),
+            }
+        }
+    }
+
+    fn factor(&mut self) -> Result<Quantity, ExpressionError> {
+        if self.eat(&Token::Minus) {
+            return Ok(-self.factor()?);
+        }
+        let base = self.primary()?;
+        if !self.eat(&Token::Caret) {
+            return Ok(base);
+        }
+        let negative = self.eat(&Token::Minus);
+        let exponent = match self.next()? {
+            Token::Number(text) => text
+                .parse::<i32>()
+                .map_err(|_| ExpressionError::InvalidNumber(text.clone()))?,
+            token => return Err(ExpressionError::UnexpectedToken(token.to_string())),
+        };
+        let exponent = if negative { -exponent } else { exponent };
+        let out = base.powi(exponent);
+        // Either the value or its SI scale no longer fits in an f64.
+        if (base.value().is_finite() && !out.value().is_finite()) || !si_scale(out.dims()).is_normal()
+        {
+            return Err(ExpressionError::ExponentOutOfRange(exponent));
+        }
+        Ok(out)
+    }
+
+    fn primary(&mut self) -> Result<Quantity, ExpressionError> {
+        match self.next()? {
+            Token::Number(text) => text
+                .replace('_', "")
+                .parse::<f64>()
+                .map(Quantity::dimensionless)
+                .map_err(|_| ExpressionError::InvalidNumber(text.clone())),
+            Token::Ident(name) => self.lookup(name),
+            Token::LeftParen => {
+                let out = self.expression()?;
+                match self.next()? {
+                    Token::RightParen => Ok(out),
+                    token => Err(ExpressionError::UnexpectedToken(token.to_string())),
+                }
+            }
+            token => Err(ExpressionError::UnexpectedToken(token.to_string())),
+        }
+    }
+
+    fn lookup(&self, name: &str) -> Result<Quantity, ExpressionError> {
+        if let Some(value) = self.variables.get(name) {
+            return Ok(*value);
+        }
+        let unit = StandardUnit::all().find(|unit| {
+            unit.symbol() == name || unit.name() == name || unit.symbol().replace('µ', "u") == name
+        });
+        if let Some(unit) = unit {
+            return Ok(Quantity::from_unit(1.0, unit));
+        }
+        Ok(match name {
+            "c" => constants::SPEED_OF_LIGHT.into(),
+            "G" => constants::GRAVITATIONAL_CONSTANT.into(),
+            "planck" => constants::PLANCK_CONSTANT.into(),
+            "hbar" => constants::REDUCED_PLANCK_CONSTANT.into(),
+            "k_B" => constants::BOLTZMANN_CONSTANT.into(),
+            "N_A" => constants::AVOGADRO_CONSTANT.into(),
+            "R" => constants::MOLAR_GAS_CONSTANT.into(),
+            "e" => constants::ELEMENTARY_CHARGE.into(),
+            "sigma" => constants::STEFAN_BOLTZMANN_CONSTANT.into(),
+            "eps0" => constants::VACUUM_PERMITTIVITY.into(),
+            "mu0" => constants::VACUUM_PERMEABILITY.into(),
+            "g0" => constants::STANDARD_GRAVITY.into(),
+            _ => {
+                let unit = self
+                    .registry
+                    .parse_prefixed(&name.replace('µ', "u"))
+                    .ok_or_else(|| ExpressionError::UnknownName(name.to_string()))?;
+                Quantity::new(unit.amount_of_base_as(), unit.dims())
+            }
+        })
+    }
+}
+
+/// A SI base unit's symbol, its size in base units and the power of its dimension.
+type SiFactor = (&'static str, f64, fn(UnitDims) -> DimsType);
+
+/// Temperature, amount and luminous intensity base units already are SI.
+fn si_factors() -> [SiFactor; 7] {
+    [
+        ("kg", MassUnit::Kilogram.amount_of_base_as(), |dims| {
+            dims.mass
+        }),
+        ("m", LengthUnit::Meter.amount_of_base_as(), |dims| {
+            dims.length
+        }),
+        ("s", TimeUnit::Second.amount_of_base_as(), |dims| dims.time),
+        (
+            "A",
+            ElectricCurrentUnit::Ampere.amount_of_base_as(),
+            |dims| dims.electric_current,
+        ),
+        ("K", 1.0, |dims| dims.temperature),
+        ("mol", 1.0, |dims| dims.amount),
+        ("cd", 1.0, |dims| dims.luminous_intensity),
+    ]
+}
+
+/// The size of the SI unit of `dims` in base units.
+fn si_scale(dims: UnitDims) -> f64 {
+    si_factors()
+        .iter()
+        .map(|(_, factor, power)| crate::units::__private::powi(*factor, power(dims) as i32))
+        .product()
+}
+
+fn write_si(f: &mut fmt::Formatter<'_>, quantity: Quantity) -> fmt::Result {
+    let dims = quantity.dims();
+    write_number(f, quantity.value() / si_scale(dims))?;
+    if dims != UnitDims::DIMENSIONLESS {
+        write!(f, " ")?;
+        write_si_dims(f, dims)?;
+    }
+    Ok(())
+}
+
+fn write_si_dims(f: &mut fmt::Formatter<'_>, dims: UnitDims) -> fmt::Result {
+    let mut first = true;
+    for (symbol, _, power) in &si_factors() {
+        let power = power(dims);
+        if power == 0 {
+            continue;
+        }
+        if !first {
+            write!(f, " ")?;
+        }
+        first = false;
+        if power == 1 {
+            write!(f, "{}", symbol)?;
+        } else {
+            write!(f, "{}^{}", symbol, power)?;
+        }
+    }
+    if first {
+        write!(f, "1")?;
+    }
+    Ok(())
+}
+
+/// Writes `value` rounded to 12 significant digits to hide floating point noise.
+fn write_number(f: &mut fmt::Formatter<'_>, value: f64) -> fmt::Result {
+    let value: f64 = format!("{:.11e}", value).parse().unwrap_or(value);
+    let magnitude = value.abs();
+    if magnitude != 0.0 && !(1e-4..1e9).contains(&magnitude) {
+        write!(f, "{:e}", value)
+    } else {
+        write!(f, "{}", value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::units::*;
+
+    fn evaluate(calculator: &mut Calculator, line: &str) -> String {
+        calculator.evaluate(line).unwrap().to_string()
+    }
+
+    fn value_in(line: &str) -> f64 {
+        let answer = Calculator::new().evaluate(line).unwrap();
+        let (_, unit) = answer.unit.unwrap();
+        answer.quantity.value() / unit.value()
+    }
+
+    #[test]
+    fn evaluate_test() {
+        let mut calculator = Calculator::new();
+        assert_eq!("3.5 km", evaluate(&mut calculator, "3 km + 500 m in km"));
+        assert_eq!("25 m s^-1", evaluate(&mut calculator, "3 km / (2 min)"));
+        assert_eq!("1500 m", evaluate(&mut calculator, "ans * 60 s"));
+        assert_eq!("-4 m^2", evaluate(&mut calculator, "-(2 m)^2"));
+        assert_eq!("9.81 m s^-2", evaluate(&mut calculator, "9.81 m/s^2"));
+        assert_eq!(
+            "5 km s^-1",
+            evaluate(&mut calculator, "5000 m s^-1 in km s^-1")
+        );
+
+        let minutes = value_in("3 AU / c in Minute");
+        assert!((minutes - 24.950_239).abs() < 1e-6, "{}", minutes);
+        let gravity = value_in("(2 EarthMass) * G / (6371 km)^2 in m/s^2");
+        assert!((gravity - 19.640_605).abs() < 1e-6, "{}", gravity);
+        assert!((value_in("1 ly in au") - 63_241.077).abs() < 1e-3);
+        assert!((value_in("1 µm + 1 um in nm") - 2000.0).abs() < 1e-9);
+        assert!((value_in("3 Gm / c in s") - 10.006_922).abs() < 1e-6);
+        assert!((value_in("1 kA in mA") - 1e6).abs() < 1e-6);
+    }
+
+    #[test]
+    fn variable_test() {
+        let mut calculator = Calculator::new();
+        assert_eq!(
+            "burn = 120 s",
+            evaluate(&mut calculator, "let burn = 2 Minute")
+        );
+        assert_eq!(
+            "thrust = 5 m s^-2",
+            evaluate(&mut calculator, "thrust = 5 m/s^2")
+        );
+        assert_eq!(
+            "36 km",
+            evaluate(&mut calculator, "thrust * burn^2 / 2 in km")
+        );
+        assert_eq!(3, calculator.variables().count());
+    }
+
+    #[test]
+    fn registry_test() {
+        let mut registry = UnitRegistry::new();
+        registry
+            .register(
+                "Klick",
+                "klick",
+                UnitDims::LENGTH,
+                LengthUnit::KILOMETER_FACTOR,
+            )
+            .unwrap();
+        let mut calculator = Calculator::with_registry(registry);
+        assert_eq!("3 km", evaluate(&mut calculator, "3 klick in km"));
+        assert_eq!("2 m", evaluate(&mut calculator, "2 mklick in m"));
+        assert_eq!(
+            Err(ExpressionError::UnknownName("klick".to_string())),
+            Calculator::new().evaluate("3 klick")
+        );
+    }
+
+    #[test]
+    fn error_test() {
+        let mut calculator = Calculator::new();
+        assert_eq!(
+            Err(ExpressionError::DimensionMismatch(
+                UnitDims::LENGTH,
+                UnitDims::TIME
+            )),
+            calculator.evaluate("3 km + 2 s")
+        );
+        assert_eq!(
+            "dimension mismatch: [m] and [s]",
+            calculator.evaluate("3 km in s").unwrap_err().to_string()
+        );
+        assert_eq!(
+            Err(ExpressionError::UnknownName("parsec".to_string())),
+            calculator.evaluate("3 parsec")
+        );
+        assert_eq!(
+            Err(ExpressionError::UnknownName("da".to_string())),
+            calculator.evaluate("3 da")
+        );
+        assert_eq!(
+            Err(ExpressionError::UnexpectedEnd),
+            calculator.evaluate("(3 km")
+        );
+        assert_eq!(
+            Err(ExpressionError::UnexpectedToken(")".to_string())),
+            calculator.evaluate("3 km)")
+        );
+        assert_eq!(
+            Err(ExpressionError::ExponentOutOfRange(1000)),
+            calculator.evaluate("(1 m)^1000")
+        );
+        assert_eq!(
+            Err(ExpressionError::ExponentOutOfRange(-1000)),
+            calculator.evaluate("(1 m)^-1000")
+        );
+        assert_eq!(
+            "exponent 1000 is out of range",
+            calculator.evaluate("m^1000").unwrap_err().to_string()
+        );
+        assert_eq!("0", evaluate(&mut Calculator::new(), "(1e-300)^2"));
+        assert_eq!(0, calculator.variables().count());
+    }
+}
@@ -0,0 +1,285 @@
+use core::fmt;
+
+use crate::units::{ExactValue, UnitError};
+
+/// An exact, positive, `const` usable rational amount of a base unit.
+///
+/// Backs the `const` unit tables, amounts too large for `u128` such as prefixed astronomical
+/// units only exist as [`ExactValue`]s.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Factor {
+    numerator: u128,
+    denominator: u128,
+}
+impl Factor {
+    pub const ONE: Self = Self::integer(1);
+
+    pub const fn new(numerator: u128, denominator: u128) -> Self {
+        if numerator == 0 || denominator == 0 {
+            panic!("Factor must be positive!")
+        }
+        let divisor = gcd(numerator, denominator);
+        Self {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        }
+    }
+
+    pub const fn integer(value: u128) -> Self {
+        Self::new(value, 1)
+    }
+
+    /// Parses a decimal literal such as `1000`, `28.3495` or `5.9722e24` at compile time.
+    pub const fn from_decimal(text: &str) -> Self {
+        match parse_decimal(text) {
+            Ok(out) => out,
+            Err(DecimalError::Invalid(_)) => panic!("Invalid factor literal!"),
+            Err(DecimalError::Zero) => panic!("Factor must be positive!"),
+            Err(DecimalError::Overflow) => panic!("Factor overflow!"),
+        }
+    }
+
+    /// Like [`from_decimal`](Self::from_decimal) but returns [`None`] if the literal doesn't fit.
+    pub const fn checked_from_decimal(text: &str) -> Option<Self> {
+        match parse_decimal(text) {
+            Ok(out) => Some(out),
+            Err(DecimalError::Invalid(_)) => panic!("Invalid factor literal!"),
+            Err(DecimalError::Zero) => panic!("Factor must be positive!"),
+            Err(DecimalError::Overflow) => None,
+        }
+    }
+
+    /// Like [`from_decimal`](Self::from_decimal) but returns an error for invalid, zero or too
+    /// large literals.
+    pub fn try_from_decimal(text: &str) -> Result<Self, UnitError> {
+        parse_decimal(text).map_err(|error| match error {
+            DecimalError::Invalid(index) => UnitError::Parse {
+                message: "invalid factor literal",
+                span: index..index + text[index..].chars().next().map_or(0, char::len_utf8),
+            },
+            DecimalError::Zero => UnitError::Parse {
+                message: "factor must be positive",
+                span: 0..text.len(),
+            },
+            DecimalError::Overflow => UnitError::Overflow,
+        })
+    }
+
+    pub const fn numerator(self) -> u128 {
+        self.numerator
+    }
+
+    pub const fn denominator(self) -> u128 {
+        self.denominator
+    }
+
+    pub const fn as_integer(self) -> Option<u128> {
+        if self.denominator == 1 {
+            Some(self.numerator)
+        } else {
+            None
+        }
+    }
+
+    /// Like [`as_integer`](Self::as_integer) but panics, which fails compilation in `const`
+    /// contexts.
+    pub const fn to_integer(self) -> u128 {
+        if self.denominator != 1 {
+            panic!("Factor is not an integer!")
+        }
+        self.numerator
+    }
+
+    pub fn try_to_integer(self) -> Result<u128, UnitError> {
+        self.as_integer().ok_or(UnitError::PrecisionLoss)
+    }
+
+    pub const fn mul(self, rhs: Self) -> Self {
+        match self.checked_mul(rhs) {
+            Some(out) => out,
+            None => panic!("Factor overflow!"),
+        }
+    }
+
+    pub fn try_mul(self, rhs: Self) -> Result<Self, UnitError> {
+        self.checked_mul(rhs).ok_or(UnitError::Overflow)
+    }
+
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let left = gcd(self.numerator, rhs.denominator);
+        let right = gcd(rhs.numerator, self.denominator);
+        let numerator = (self.numerator / left).checked_mul(rhs.numerator / right);
+        let denominator = (self.denominator / right).checked_mul(rhs.denominator / left);
+        match (numerator, denominator) {
+            (Some(numerator), Some(denominator)) => Some(Self {
+                numerator,
+                denominator,
+            }),
+            _ => None,
+        }
+    }
+
+    pub const fn recip(self) -> Self {
+        Self {
+            numerator: self.denominator,
+            denominator: self.numerator,
+        }
+    }
+
+    pub fn to_exact(self) -> ExactValue {
+        ExactValue::new(self.numerator.into(), self.denominator.into())
+    }
+}
+impl fmt::Display for Factor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+enum DecimalError {
+    /// The byte index of the first invalid character.
+    Invalid(usize),
+    Zero,
+    Overflow,
+}
+
+const fn parse_decimal(text: &str) -> Result<Factor, DecimalError> {
+    let bytes = text.as_bytes();
+    let mut numerator: u128 = 0;
+    let mut fraction_digits: i32 = 0;
+    let mut in_fraction = false;
+    let mut index = 0;
+    while index < bytes.len() && bytes[index] != b'e' && bytes[index] != b'E' {
+        match bytes[index] {
+            b'_' => {}
+            b'.' if !in_fraction => in_fraction = true,
+            digit @ b'0'..=b'9' => {
+                numerator = match numerator.checked_mul(10) {
+                    Some(value) => match value.checked_add((digit - b'0') as u128) {
+                        Some(value) => value,
+                        None => return Err(DecimalError::Overflow),
+                    },
+                    None => return Err(DecimalError::Overflow),
+                };
+                if in_fraction {
+                    fraction_digits += 1;
+                }
+            }
+            _ => return Err(DecimalError::Invalid(index)),
+        }
+        index += 1;
+    }
+
+    let mut exponent: i32 = 0;
+    if index < bytes.len() {
+        index += 1;
+        let negative = index < bytes.len() && bytes[index] == b'-';
+        if index < bytes.len() && (bytes[index] == b'-' || bytes[index] == b'+') {
+            index += 1;
+        }
+        while index < bytes.len() {
+            match bytes[index] {
+                b'_' => {}
+                digit @ b'0'..=b'9' if exponent < 1000 => {
+                    exponent = exponent * 10 + (digit - b'0') as i32
+                }
+                b'0'..=b'9' => return Err(DecimalError::Overflow),
+                _ => return Err(DecimalError::Invalid(index)),
+            }
+            index += 1;
+        }
+        if negative {
+            exponent = -exponent;
+        }
+    }
+
+    if numerator == 0 {
+        return Err(DecimalError::Zero);
+    }
+    exponent -= fraction_digits;
+    let power = match 10u128.checked_pow(exponent.unsigned_abs()) {
+        Some(power) => power,
+        None => return Err(DecimalError::Overflow),
+    };
+    if exponent >= 0 {
+        match numerator.checked_mul(power) {
+            Some(numerator) => Ok(Factor::new(numerator, 1)),
+            None => Err(DecimalError::Overflow),
+        }
+    } else {
+        Ok(Factor::new(numerator, power))
+    }
+}
+
+const fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let remainder = a % b;
+        a = b;
+        b = remainder;
+    }
+    a
+}
+
+#[cfg(test)]
+mod test {
+    use crate::units::*;
+
+    const OUNCE: Factor = Factor::from_decimal("28.3495").mul(Factor::integer(1000));
+
+    #[test]
+    fn from_decimal_test() {
+        assert_eq!(Factor::new(56699, 2), OUNCE);
+        assert_eq!(Factor::integer(1000), Factor::from_decimal("1_000"));
+        assert_eq!(
+            Factor::integer(5_972_200_000_000_000_000_000_000),
+            Factor::from_decimal("5.9722e24")
+        );
+        assert_eq!(Factor::new(3, 2000), Factor::from_decimal("1.5e-3"));
+    }
+
+    #[test]
+    fn try_test() {
+        assert_eq!(Ok(OUNCE), Factor::try_from_decimal("28_349.5"));
+        assert_eq!(
+            Err(UnitError::Parse {
+                message: "invalid factor literal",
+                span: 2..3,
+            }),
+            Factor::try_from_decimal("12x4")
+        );
+        assert!(matches!(
+            Factor::try_from_decimal("0.0"),
+            Err(UnitError::Parse { .. })
+        ));
+        assert_eq!(Err(UnitError::Overflow), Factor::try_from_decimal("1e40"));
+        assert_eq!(Err(UnitError::PrecisionLoss), OUNCE.try_to_integer());
+        assert_eq!(
+            Err(UnitError::Overflow),
+            Factor::integer(u128::MAX).try_mul(Factor::integer(2))
+        );
+    }
+
+    #[test]
+    fn const_table_test() {
+        const METER: u128 = LengthUnit::METER_FACTOR.to_integer();
+        assert_eq!(1_000_000_000, METER);
+        assert_eq!(Some(60_000_000_000), TimeUnit::MINUTE_FACTOR.as_integer());
+        assert_eq!(None, MassUnit::OZ_FACTOR.as_integer());
+        assert_eq!(LengthUnit::KILOMETER_FACTOR, LengthUnit::Kilometer.amount_of_base());
+        assert_eq!(Factor::ONE, ElectricCurrentUnit::PICOAMPERE_FACTOR);
+    }
+
+    #[test]
+    fn mul_test() {
+        let factor = Factor::new(3, 4).mul(Factor::new(8, 9));
+        assert_eq!(Factor::new(2, 3), factor);
+        assert_eq!(Factor::new(3, 2), factor.recip());
+        assert_eq!(None, Factor::integer(u128::MAX).checked_mul(Factor::integer(2)));
+        assert_eq!("2/3", factor.to_string());
+    }
+}
@@ -0,0 +1,102 @@
+use std::time::{Duration, Instant};
+
+use crate::units::{GSDateTime, GSDuration, UnitValue};
+
+/// Maps wall clock time onto game time, running `time_scale` times faster than real time.
+///
+/// Fractions of a game nanosecond are carried between ticks so a slow scale doesn't drift.
+#[derive(Copy, Clone, Debug)]
+pub struct GameClock {
+    now: GSDateTime,
+    last_tick: Instant,
+    time_scale: f64,
+    carry: f64,
+}
+impl GameClock {
+    pub fn new(start: GSDateTime, time_scale: f64, now: Instant) -> Self {
+        let mut out = Self {
+            now: start,
+            last_tick: now,
+            time_scale: 1.0,
+            carry: 0.0,
+        };
+        out.set_time_scale(time_scale);
+        out
+    }
+
+    /// The current game time.
+    pub const fn now(&self) -> GSDateTime {
+        self.now
+    }
+
+    pub const fn time_scale(&self) -> f64 {
+        self.time_scale
+    }
+
+    /// Panics if `time_scale` is negative or not finite, a scale of `0` pauses the clock.
+    pub fn set_time_scale(&mut self, time_scale: f64) {
+        assert!(
+            time_scale.is_finite() && time_scale >= 0.0,
+            "Time scale must be finite and not negative, got {}",
+            time_scale
+        );
+        self.time_scale = time_scale;
+    }
+
+    /// Advances the clock by the scaled wall time since the last tick and returns the game time
+    /// that passed. A `now` before the last tick counts as no time passing.
+    pub fn tick(&mut self, now: Instant) -> GSDuration {
+        let real = now.saturating_duration_since(self.last_tick);
+        self.last_tick = now;
+        let elapsed = self.scale(real);
+        self.now += elapsed;
+        elapsed
+    }
+
+    fn scale(&mut self, real: Duration) -> GSDuration {
+        let scaled = real.as_nanos() as f64 * self.time_scale + self.carry;
+        let whole = scaled.floor();
+        self.carry = scaled - whole;
+        UnitValue::new(whole as i128)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use crate::units::*;
+
+    #[test]
+    fn tick_test() {
+        let start = Instant::now();
+        let mut clock = GameClock::new(GSDateTime::EPOCH, 60.0, start);
+
+        let elapsed = clock.tick(start + Duration::from_secs(1));
+        assert_eq!(60_000_000_000, elapsed.into_inner());
+        assert_eq!("GS 0000-01-01 00:01", clock.now().to_string());
+
+        clock.set_time_scale(0.0);
+        assert_eq!(0, clock.tick(start + Duration::from_secs(2)).into_inner());
+        // Going backwards doesn't rewind the clock.
+        assert_eq!(0, clock.tick(start).into_inner());
+    }
+
+    #[test]
+    fn carry_test() {
+        let start = Instant::now();
+        let mut clock = GameClock::new(GSDateTime::EPOCH, 0.25, start);
+        let mut total = 0;
+        for tick in 1..=10 {
+            total += clock.tick(start + Duration::from_nanos(tick)).into_inner();
+        }
+        assert_eq!(2, total);
+        assert_eq!(2, clock.now().since_epoch().into_inner());
+    }
+
+    #[test]
+    #[should_panic]
+    fn negative_scale_test() {
+        GameClock::new(GSDateTime::EPOCH, -1.0, Instant::now());
+    }
+}
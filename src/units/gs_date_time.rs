@@ -0,0 +1,274 @@
+use core::fmt;
+use core::ops::*;
+use core::str::FromStr;
+
+use crate::units::{TimeUnit, UnitDims, UnitPoint, UnitValue};
+
+const NANOS_PER_MINUTE: i128 = TimeUnit::MINUTE_FACTOR.to_integer() as i128;
+const NANOS_PER_HOUR: i128 = TimeUnit::HOUR_FACTOR.to_integer() as i128;
+const NANOS_PER_DAY: i128 = TimeUnit::GSDAY_FACTOR.to_integer() as i128;
+const NANOS_PER_MONTH: i128 = TimeUnit::GSMONTH_FACTOR.to_integer() as i128;
+const NANOS_PER_YEAR: i128 = TimeUnit::GSYEAR_FACTOR.to_integer() as i128;
+
+pub type GSDuration = UnitValue<i128, { UnitDims::TIME }>;
+/// A point in game time, in nanoseconds since the [`GSDateTime::EPOCH`].
+pub type GSInstant = UnitPoint<i128, { UnitDims::TIME }>;
+
+/// An absolute point on the Galactic Standard calendar, stored as nanoseconds since
+/// `GS 0000-01-01 00:00`. Every year has 12 months of 30 days.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub struct GSDateTime {
+    instant: GSInstant,
+}
+impl GSDateTime {
+    pub const EPOCH: Self = Self::from_nanos(0);
+
+    const fn from_nanos(nanos: i128) -> Self {
+        Self {
+            instant: UnitPoint::new(UnitValue::new(nanos)),
+        }
+    }
+
+    /// Months and days are 1 based, returns [`None`] if any component is out of range.
+    pub fn new(year: i128, month: u8, day: u8, hour: u8, minute: u8) -> Option<Self> {
+        if !(1..=12).contains(&month) || !(1..=30).contains(&day) || hour >= 24 || minute >= 60 {
+            return None;
+        }
+        let nanos = year.checked_mul(NANOS_PER_YEAR)?.checked_add(
+            (month - 1) as i128 * NANOS_PER_MONTH
+                + (day - 1) as i128 * NANOS_PER_DAY
+                + hour as i128 * NANOS_PER_HOUR
+                + minute as i128 * NANOS_PER_MINUTE,
+        )?;
+        Some(Self::from_nanos(nanos))
+    }
+
+    pub fn from_since_epoch(since_epoch: GSDuration) -> Self {
+        Self::from_instant(UnitPoint::new(since_epoch))
+    }
+
+    pub fn since_epoch(self) -> GSDuration {
+        self.instant.into_offset()
+    }
+
+    pub const fn from_instant(instant: GSInstant) -> Self {
+        Self { instant }
+    }
+
+    pub const fn instant(self) -> GSInstant {
+        self.instant
+    }
+
+    fn nanos(self) -> i128 {
+        *self.instant.offset().value()
+    }
+
+    pub fn year(self) -> i128 {
+        self.nanos().div_euclid(NANOS_PER_YEAR)
+    }
+
+    pub fn month(self) -> u8 {
+        (self.nanos().rem_euclid(NANOS_PER_YEAR) / NANOS_PER_MONTH) as u8 + 1
+    }
+
+    pub fn day(self) -> u8 {
+        (self.nanos().rem_euclid(NANOS_PER_MONTH) / NANOS_PER_DAY) as u8 + 1
+    }
+
+    pub fn hour(self) -> u8 {
+        (self.nanos().rem_euclid(NANOS_PER_DAY) / NANOS_PER_HOUR) as u8
+    }
+
+    pub fn minute(self) -> u8 {
+        (self.nanos().rem_euclid(NANOS_PER_HOUR) / NANOS_PER_MINUTE) as u8
+    }
+
+    /// Time elapsed since the start of the current minute.
+    pub fn sub_minute(self) -> GSDuration {
+        UnitValue::new(self.nanos().rem_euclid(NANOS_PER_MINUTE))
+    }
+}
+impl Add<GSDuration> for GSDateTime {
+    type Output = Self;
+
+    fn add(self, rhs: GSDuration) -> Self::Output {
+        Self::from_instant(self.instant + rhs)
+    }
+}
+impl AddAssign<GSDuration> for GSDateTime {
+    fn add_assign(&mut self, rhs: GSDuration) {
+        self.instant += rhs;
+    }
+}
+impl Sub<GSDuration> for GSDateTime {
+    type Output = Self;
+
+    fn sub(self, rhs: GSDuration) -> Self::Output {
+        Self::from_instant(self.instant - rhs)
+    }
+}
+impl SubAssign<GSDuration> for GSDateTime {
+    fn sub_assign(&mut self, rhs: GSDuration) {
+        self.instant -= rhs;
+    }
+}
+impl Sub for GSDateTime {
+    type Output = GSDuration;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.instant - rhs.instant
+    }
+}
+impl From<GSInstant> for GSDateTime {
+    fn from(from: GSInstant) -> Self {
+        Self::from_instant(from)
+    }
+}
+impl From<GSDateTime> for GSInstant {
+    fn from(from: GSDateTime) -> Self {
+        from.instant
+    }
+}
+impl fmt::Display for GSDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let year = self.year();
+        let sign = if year < 0 { "-" } else { "" };
+        write!(
+            f,
+            "GS {}{:04}-{:02}-{:02} {:02}:{:02}",
+            sign,
+            year.abs(),
+            self.month(),
+            self.day(),
+            self.hour(),
+            self.minute()
+        )
+    }
+}
+impl FromStr for GSDateTime {
+    type Err = ParseGSDateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rest = s
+            .trim()
+            .strip_prefix("GS ")
+            .ok_or(ParseGSDateTimeError::InvalidFormat)?;
+        let (date, time) = split_once(rest.trim_start(), ' ')?;
+        let (negative, date) = match date.strip_prefix('-') {
+            Some(date) => (true, date),
+            None => (false, date),
+        };
+        let (year, date) = split_once(date, '-')?;
+        let (month, day) = split_once(date, '-')?;
+        let (hour, minute) = split_once(time, ':')?;
+
+        // The year is at least four digits, everything else exactly two, as written by Display.
+        if year.len() < 4 {
+            return Err(ParseGSDateTimeError::InvalidFormat);
+        }
+        let year = parse_component::<i128>(year)?;
+        Self::new(
+            if negative { -year } else { year },
+            parse_padded(month)?,
+            parse_padded(day)?,
+            parse_padded(hour)?,
+            parse_padded(minute)?,
+        )
+        .ok_or(ParseGSDateTimeError::OutOfRange)
+    }
+}
+
+fn split_once(s: &str, delimiter: char) -> Result<(&str, &str), ParseGSDateTimeError> {
+    s.split_once(delimiter)
+        .ok_or(ParseGSDateTimeError::InvalidFormat)
+}
+
+fn parse_padded(s: &str) -> Result<u8, ParseGSDateTimeError> {
+    if s.len() != 2 {
+        return Err(ParseGSDateTimeError::InvalidFormat);
+    }
+    parse_component(s)
+}
+
+fn parse_component<T: FromStr>(s: &str) -> Result<T, ParseGSDateTimeError> {
+    if s.is_empty() || !s.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(ParseGSDateTimeError::InvalidFormat);
+    }
+    s.parse().map_err(|_| ParseGSDateTimeError::OutOfRange)
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ParseGSDateTimeError {
+    InvalidFormat,
+    OutOfRange,
+}
+impl fmt::Display for ParseGSDateTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseGSDateTimeError::InvalidFormat => {
+                write!(f, "expected a date of the form `GS YYYY-MM-DD HH:MM`")
+            }
+            ParseGSDateTimeError::OutOfRange => write!(f, "date component out of range"),
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for ParseGSDateTimeError {}
+
+#[cfg(test)]
+mod test {
+    use crate::units::{GSDateTime, GSInstant, UnitValue};
+
+    #[test]
+    fn components_test() {
+        let date_time = GSDateTime::new(3021, 4, 12, 8, 15).unwrap();
+        assert_eq!(3021, date_time.year());
+        assert_eq!(4, date_time.month());
+        assert_eq!(12, date_time.day());
+        assert_eq!(8, date_time.hour());
+        assert_eq!(15, date_time.minute());
+        assert_eq!("GS 3021-04-12 08:15", date_time.to_string());
+        assert_eq!(None, GSDateTime::new(3021, 13, 1, 0, 0));
+        assert_eq!(None, GSDateTime::new(3021, 1, 31, 0, 0));
+    }
+
+    #[test]
+    fn arithmetic_test() {
+        let date_time = GSDateTime::new(3021, 12, 30, 23, 59).unwrap();
+        let later = date_time + UnitValue::new(60 * 1_000_000_000);
+        assert_eq!("GS 3022-01-01 00:00", later.to_string());
+        assert_eq!(60 * 1_000_000_000, *(later - date_time).value());
+        assert_eq!(date_time, later - (later - date_time));
+
+        let instant = GSInstant::from(later);
+        assert_eq!(later - date_time, instant - date_time.instant());
+        assert_eq!(later, GSDateTime::from(instant));
+
+        let before_epoch = GSDateTime::EPOCH - UnitValue::new(1);
+        assert_eq!("GS -0001-12-30 23:59", before_epoch.to_string());
+    }
+
+    #[test]
+    fn parse_test() {
+        for text in &[
+            "GS 3021-04-12 08:15",
+            "GS -0001-12-30 23:59",
+            "GS 0000-01-01 00:00",
+        ] {
+            assert_eq!(*text, text.parse::<GSDateTime>().unwrap().to_string());
+        }
+        assert!("3021-04-12 08:15".parse::<GSDateTime>().is_err());
+        assert!("GS 3021-04-31 08:15".parse::<GSDateTime>().is_err());
+        assert!("GS 3021-04-12 8-15".parse::<GSDateTime>().is_err());
+        assert!("GS 3021-4-12 08:15".parse::<GSDateTime>().is_err());
+        assert!("GS 3021-04-12 08:5".parse::<GSDateTime>().is_err());
+        assert!("GS 21-04-12 08:15".parse::<GSDateTime>().is_err());
+        assert_eq!(
+            "GS 12021-04-12 08:15",
+            "GS 12021-04-12 08:15"
+                .parse::<GSDateTime>()
+                .unwrap()
+                .to_string()
+        );
+    }
+}
@@ -0,0 +1,478 @@
+//! Numerical integrators whose derivatives are dimension checked, `dx/dt` of a state with dims
+//! `DIMS` must have dims `DIMS - TIME`.
+//!
+//! ```compile_fail,E0308
+//! # #![feature(adt_const_params, generic_const_exprs)]
+//! # #![allow(incomplete_features)]
+//! # use space_rpg::units::{FirstOrderMethod, Length, Time, UnitValue};
+//! let position: Length<f64> = UnitValue::new(0.0);
+//! // The derivative of a length must be a velocity, not a length.
+//! FirstOrderMethod::Euler.step(position, Time::new(0.0), Time::new(1.0), |_, x| x);
+//! ```
+
+use core::fmt;
+
+use crate::units::{Time, UnitDims, UnitValue, UnitVector};
+
+/// A state that can be integrated over time.
+pub trait Integrable: Copy {
+    /// The rate of change, with dims of the state minus [`UnitDims::TIME`].
+    type Derivative: Copy;
+    /// The type of an error tolerance, with the same dims as the state.
+    type Tolerance: Copy;
+
+    /// `self + derivative * dt`
+    fn advance(self, derivative: Self::Derivative, dt: Time<f64>) -> Self;
+
+    /// The largest difference between the components of two states as a multiple of
+    /// `tolerance`.
+    fn error_ratio(self, other: Self, tolerance: Self::Tolerance) -> f64;
+}
+impl<const DIMS: UnitDims> Integrable for UnitValue<f64, DIMS>
+where
+    [(); DIMS.sub(UnitDims::TIME).convert_for_const()]: ,
+{
+    type Derivative = UnitValue<f64, { DIMS.sub(UnitDims::TIME) }>;
+    type Tolerance = Self;
+
+    fn advance(self, derivative: Self::Derivative, dt: Time<f64>) -> Self {
+        UnitValue::new(self.into_inner() + derivative.into_inner() * dt.into_inner())
+    }
+
+    fn error_ratio(self, other: Self, tolerance: Self::Tolerance) -> f64 {
+        (self.into_inner() - other.into_inner()).abs() / tolerance.into_inner()
+    }
+}
+impl<const DIMS: UnitDims, const N: usize> Integrable for UnitVector<f64, DIMS, N>
+where
+    [(); DIMS.sub(UnitDims::TIME).convert_for_const()]: ,
+{
+    type Derivative = UnitVector<f64, { DIMS.sub(UnitDims::TIME) }, N>;
+    type Tolerance = UnitValue<f64, DIMS>;
+
+    fn advance(self, derivative: Self::Derivative, dt: Time<f64>) -> Self {
+        let dt = dt.into_inner();
+        let mut components = self.into_inner();
+        for (component, rate) in components.iter_mut().zip(derivative.components()) {
+            *component += rate * dt;
+        }
+        UnitVector::new(components)
+    }
+
+    fn error_ratio(self, other: Self, tolerance: Self::Tolerance) -> f64 {
+        self.components()
+            .iter()
+            .zip(other.components())
+            .map(|(l, r)| (l - r).abs())
+            .fold(0.0, f64::max)
+            / tolerance.into_inner()
+    }
+}
+
+/// The derivative of the derivative of `X`.
+pub type SecondDerivative<X> = <<X as Integrable>::Derivative as Integrable>::Derivative;
+
+/// Fixed step methods for `dx/dt = f(t, x)`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum FirstOrderMethod {
+    /// Explicit Euler, first order.
+    Euler,
+    /// Classic Runge-Kutta, fourth order.
+    Rk4,
+}
+impl FirstOrderMethod {
+    pub fn step<X, F>(self, x: X, t: Time<f64>, dt: Time<f64>, mut f: F) -> X
+    where
+        X: Integrable,
+        F: FnMut(Time<f64>, X) -> X::Derivative,
+    {
+        match self {
+            FirstOrderMethod::Euler => x.advance(f(t, x), dt),
+            FirstOrderMethod::Rk4 => {
+                let half = dt * 0.5;
+                let k1 = f(t, x);
+                let k2 = f(t + half, x.advance(k1, half));
+                let k3 = f(t + half, x.advance(k2, half));
+                let k4 = f(t + dt, x.advance(k3, dt));
+                x.advance(k1, dt / 6.0)
+                    .advance(k2, dt / 3.0)
+                    .advance(k3, dt / 3.0)
+                    .advance(k4, dt / 6.0)
+            }
+        }
+    }
+
+    /// Integrates from `from` to `to` in equal steps no longer than `max_step`, panics if
+    /// `max_step` isn't positive.
+    pub fn integrate<X, F>(
+        self,
+        mut x: X,
+        from: Time<f64>,
+        to: Time<f64>,
+        max_step: Time<f64>,
+        mut f: F,
+    ) -> X
+    where
+        X: Integrable,
+        F: FnMut(Time<f64>, X) -> X::Derivative,
+    {
+        for (t, dt) in fixed_steps(from, to, max_step) {
+            x = self.step(x, t, dt, &mut f);
+        }
+        x
+    }
+}
+
+/// Fixed step methods for `d²x/dt² = a(t, x)`, where the acceleration only depends on the
+/// position, e.g. gravity or springs.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum SecondOrderMethod {
+    /// Symplectic Euler, updates the velocity then moves with the new velocity.
+    SemiImplicitEuler,
+    /// Velocity Verlet, second order and symplectic.
+    VelocityVerlet,
+}
+impl SecondOrderMethod {
+    /// Returns the new position and velocity.
+    pub fn step<X, A>(
+        self,
+        x: X,
+        v: X::Derivative,
+        t: Time<f64>,
+        dt: Time<f64>,
+        mut a: A,
+    ) -> (X, X::Derivative)
+    where
+        X: Integrable,
+        X::Derivative: Integrable,
+        A: FnMut(Time<f64>, X) -> SecondDerivative<X>,
+    {
+        match self {
+            SecondOrderMethod::SemiImplicitEuler => {
+                let v = v.advance(a(t, x), dt);
+                (x.advance(v, dt), v)
+            }
+            SecondOrderMethod::VelocityVerlet => {
+                let half = dt * 0.5;
+                let half_v = v.advance(a(t, x), half);
+                let x = x.advance(half_v, dt);
+                (x, half_v.advance(a(t + dt, x), half))
+            }
+        }
+    }
+
+    /// Integrates from `from` to `to` in equal steps no longer than `max_step`, panics if
+    /// `max_step` isn't positive.
+    pub fn integrate<X, A>(
+        self,
+        mut x: X,
+        mut v: X::Derivative,
+        from: Time<f64>,
+        to: Time<f64>,
+        max_step: Time<f64>,
+        mut a: A,
+    ) -> (X, X::Derivative)
+    where
+        X: Integrable,
+        X::Derivative: Integrable,
+        A: FnMut(Time<f64>, X) -> SecondDerivative<X>,
+    {
+        for (t, dt) in fixed_steps(from, to, max_step) {
+            let (next_x, next_v) = self.step(x, v, t, dt, &mut a);
+            x = next_x;
+            v = next_v;
+        }
+        (x, v)
+    }
+}
+
+/// The start and length of equal steps covering `from..to`.
+fn fixed_steps(
+    from: Time<f64>,
+    to: Time<f64>,
+    max_step: Time<f64>,
+) -> impl Iterator<Item = (Time<f64>, Time<f64>)> {
+    assert!(
+        *max_step.value() > 0.0,
+        "Step must be positive, got {:?}",
+        max_step
+    );
+    let span = to - from;
+    let steps = (span.value().abs() / max_step.value()).ceil() as usize;
+    (0..steps).map(move |step| {
+        let t = from + span * (step as f64 / steps as f64);
+        let next = from + span * ((step + 1) as f64 / steps as f64);
+        (t, next - t)
+    })
+}
+
+/// Dormand-Prince 5(4), which picks its own step size to keep the error estimate of each step
+/// within `tolerance`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AdaptiveRk45<X>
+where
+    X: Integrable,
+{
+    pub tolerance: X::Tolerance,
+    /// Fails rather than taking steps shorter than this, 1 ns by default.
+    pub min_step: Time<f64>,
+}
+impl<X> AdaptiveRk45<X>
+where
+    X: Integrable,
+{
+    const C: [f64; 6] = [1.0 / 5.0, 3.0 / 10.0, 4.0 / 5.0, 8.0 / 9.0, 1.0, 1.0];
+    const A: [&'static [f64]; 6] = [
+        &[1.0 / 5.0],
+        &[3.0 / 40.0, 9.0 / 40.0],
+        &[44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0],
+        &[
+            19372.0 / 6561.0,
+            -25360.0 / 2187.0,
+            64448.0 / 6561.0,
+            -212.0 / 729.0,
+        ],
+        &[
+            9017.0 / 3168.0,
+            -355.0 / 33.0,
+            46732.0 / 5247.0,
+            49.0 / 176.0,
+            -5103.0 / 18656.0,
+        ],
+        &[
+            35.0 / 384.0,
+            0.0,
+            500.0 / 1113.0,
+            125.0 / 192.0,
+            -2187.0 / 6784.0,
+            11.0 / 84.0,
+        ],
+    ];
+    /// Fourth order weights, the fifth order ones are the last row of `A`.
+    const B4: [f64; 7] = [
+        5179.0 / 57600.0,
+        0.0,
+        7571.0 / 16695.0,
+        393.0 / 640.0,
+        -92097.0 / 339200.0,
+        187.0 / 2100.0,
+        1.0 / 40.0,
+    ];
+
+    pub fn new(tolerance: X::Tolerance) -> Self {
+        Self {
+            tolerance,
+            min_step: UnitValue::new(1.0),
+        }
+    }
+
+    /// Takes a single step of `dt`, returning the fifth order result and its error as a
+    /// multiple of the tolerance.
+    pub fn step<F>(&self, x: X, t: Time<f64>, dt: Time<f64>, mut f: F) -> (X, f64)
+    where
+        F: FnMut(Time<f64>, X) -> X::Derivative,
+    {
+        let combine = |k: &[X::Derivative], weights: &[f64]| {
+            k.iter()
+                .zip(weights)
+                .filter(|(_, &weight)| weight != 0.0)
+                .fold(x, |x, (&k, &weight)| x.advance(k, dt * weight))
+        };
+        let mut k = [f(t, x); 7];
+        for (stage, (c, a)) in Self::C.iter().zip(Self::A.iter()).enumerate() {
+            k[stage + 1] = f(t + dt * *c, combine(&k[..=stage], a));
+        }
+        let fifth = combine(&k[..6], Self::A[5]);
+        let fourth = combine(&k, &Self::B4);
+        (fifth, fifth.error_ratio(fourth, self.tolerance))
+    }
+
+    /// Integrates from `from` to `to` starting with steps of `initial_step`.
+    pub fn integrate<F>(
+        &self,
+        mut x: X,
+        from: Time<f64>,
+        to: Time<f64>,
+        initial_step: Time<f64>,
+        mut f: F,
+    ) -> Result<X, IntegrationError>
+    where
+        F: FnMut(Time<f64>, X) -> X::Derivative,
+    {
+        let direction = (to - from).value().signum();
+        let mut t = from;
+        let mut dt = initial_step.value().abs();
+        while t != to {
+            let remaining = (to - t).value().abs();
+            let last = dt >= remaining;
+            if last {
+                dt = remaining;
+            }
+            let (next, error) = self.step(x, t, UnitValue::new(dt * direction), &mut f);
+            if error <= 1.0 {
+                x = next;
+                t = if last {
+                    to
+                } else {
+                    t + UnitValue::new(dt * direction)
+                };
+            } else if dt <= *self.min_step.value() {
+                return Err(IntegrationError::StepTooSmall { time: t });
+            }
+            // The usual safety factor, growing at most 5 and shrinking at most 5 times.
+            let factor = if error > 0.0 {
+                0.9 * error.powf(-0.2)
+            } else {
+                5.0
+            };
+            dt = (dt * factor.clamp(0.2, 5.0)).max(*self.min_step.value());
+        }
+        Ok(x)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum IntegrationError {
+    /// The error stayed above the tolerance at the minimum step.
+    StepTooSmall { time: Time<f64> },
+}
+impl fmt::Display for IntegrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntegrationError::StepTooSmall { time } => {
+                write!(f, "step size fell below the minimum at {} ns", time.value())
+            }
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for IntegrationError {}
+
+#[cfg(test)]
+mod test {
+    use crate::units::*;
+
+    const SECOND: f64 = 1e9;
+
+    fn seconds(value: f64) -> Time<f64> {
+        UnitValue::new(value * SECOND)
+    }
+
+    /// `dx/dt = -x / tau`, so `x = e^(-t / tau)`.
+    fn decay_error(method: FirstOrderMethod, step: f64) -> f64 {
+        let tau = seconds(2.0);
+        let x: Length<f64> = UnitValue::new(1.0);
+        let end = method.integrate(x, seconds(0.0), seconds(4.0), seconds(step), |_, x| {
+            x / tau * -1.0
+        });
+        (end.into_inner() - (-2.0f64).exp()).abs()
+    }
+
+    #[test]
+    fn first_order_test() {
+        let euler = decay_error(FirstOrderMethod::Euler, 0.01);
+        assert!(euler < 1e-3, "{}", euler);
+        let ratio = euler / decay_error(FirstOrderMethod::Euler, 0.005);
+        assert!((ratio - 2.0).abs() < 0.1, "{}", ratio);
+
+        let rk4 = decay_error(FirstOrderMethod::Rk4, 0.1);
+        assert!(rk4 < 1e-6, "{}", rk4);
+        let ratio = rk4 / decay_error(FirstOrderMethod::Rk4, 0.05);
+        assert!((ratio - 16.0).abs() < 1.0, "{}", ratio);
+
+        // Time dependent rates and integrating backwards.
+        let start: Length<f64> = UnitValue::new(1.0);
+        let rate: UnitValue<f64, { UnitDims::LENGTH.sub(UnitDims::TIME.mul(2)) }> =
+            UnitValue::new(2.0 / SECOND / SECOND);
+        let end = FirstOrderMethod::Rk4.integrate(
+            start,
+            seconds(3.0),
+            seconds(1.0),
+            seconds(0.5),
+            |t, _| rate * t,
+        );
+        assert!((end.into_inner() + 7.0).abs() < 1e-9, "{:?}", end);
+    }
+
+    #[test]
+    fn second_order_test() {
+        // A spring with a period of 2π seconds, `x = cos(t)`.
+        fn acceleration(
+            _: Time<f64>,
+            x: UnitVector2<f64, { UnitDims::LENGTH }>,
+        ) -> UnitVector2<f64, { UnitDims::ACCELERATION }> {
+            let second = seconds(1.0);
+            -(x / second / second)
+        }
+        let x = UnitVector2::<f64, { UnitDims::LENGTH }>::new([1.0, 0.0]);
+        let v = UnitVector2::new([0.0, 0.0]);
+        let end = 20.0 * core::f64::consts::PI;
+
+        let (verlet_x, verlet_v) = SecondOrderMethod::VelocityVerlet.integrate(
+            x,
+            v,
+            seconds(0.0),
+            seconds(end),
+            seconds(0.01),
+            acceleration,
+        );
+        assert!(
+            (verlet_x.components()[0] - 1.0).abs() < 1e-3,
+            "{:?}",
+            verlet_x
+        );
+        assert!(
+            verlet_v.components()[0].abs() * SECOND < 1e-3,
+            "{:?}",
+            verlet_v
+        );
+
+        // Symplectic Euler keeps the energy bounded rather than drifting.
+        let (euler_x, euler_v) = SecondOrderMethod::SemiImplicitEuler.integrate(
+            x,
+            v,
+            seconds(0.0),
+            seconds(end),
+            seconds(0.01),
+            acceleration,
+        );
+        let energy = euler_x.components()[0].powi(2) + (euler_v.components()[0] * SECOND).powi(2);
+        assert!((energy - 1.0).abs() < 0.02, "{}", energy);
+    }
+
+    #[test]
+    fn adaptive_test() {
+        let tau = seconds(2.0);
+        let x: Length<f64> = UnitValue::new(1.0);
+        let rk45 = AdaptiveRk45::new(UnitValue::new(1e-10));
+        let mut evaluations = 0;
+        let end = rk45
+            .integrate(x, seconds(0.0), seconds(4.0), seconds(0.1), |_, x| {
+                evaluations += 1;
+                x / tau * -1.0
+            })
+            .unwrap();
+        assert!(
+            (end.into_inner() - (-2.0f64).exp()).abs() < 1e-8,
+            "{:?}",
+            end
+        );
+        assert!(evaluations < 500, "{}", evaluations);
+
+        // A single step reports its error relative to the tolerance.
+        let (_, error) = rk45.step(x, seconds(0.0), seconds(1.0), |_, x| x / tau * -1.0);
+        assert!(error > 1.0, "{}", error);
+        let (_, error) = rk45.step(x, seconds(0.0), seconds(0.01), |_, x| x / tau * -1.0);
+        assert!(error < 1.0, "{}", error);
+
+        let strict = AdaptiveRk45 {
+            tolerance: UnitValue::new(1e-30),
+            min_step: seconds(0.1),
+        };
+        assert_eq!(
+            Err(IntegrationError::StepTooSmall { time: seconds(0.0) }),
+            strict.integrate(x, seconds(0.0), seconds(4.0), seconds(1.0), |_, x| x / tau
+                * -1.0)
+        );
+    }
+}
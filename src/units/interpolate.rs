@@ -0,0 +1,337 @@
+use alloc::vec::Vec;
+
+use crate::units::{Dimensionless, KindTag, Time, UnitDims, UnitValue, UnitVector};
+
+/// Values that can be blended, `t` is `0` at `self` and `1` at `to` and may go outside that
+/// range to extrapolate.
+pub trait Interpolate: Copy {
+    fn lerp(self, to: Self, t: f64) -> Self;
+
+    fn ease(self, to: Self, t: f64, easing: Easing) -> Self {
+        self.lerp(to, easing.apply(t))
+    }
+}
+impl<const DIMS: UnitDims, K> Interpolate for UnitValue<f64, DIMS, K>
+where
+    K: KindTag,
+{
+    fn lerp(self, to: Self, t: f64) -> Self {
+        self + (to - self) * t
+    }
+}
+impl<const DIMS: UnitDims, const N: usize> Interpolate for UnitVector<f64, DIMS, N> {
+    fn lerp(self, to: Self, t: f64) -> Self {
+        self + (to - self) * t
+    }
+}
+
+impl<const DIMS: UnitDims, K> UnitValue<f64, DIMS, K>
+where
+    K: KindTag,
+{
+    /// Where `value` lies between `self` and `to`, the inverse of [`Interpolate::lerp`]. An empty
+    /// range gives `0`.
+    pub fn inverse_lerp(self, to: Self, value: Self) -> Dimensionless<f64> {
+        let range = to.into_inner() - self.into_inner();
+        if range == 0.0 {
+            return UnitValue::new(0.0);
+        }
+        UnitValue::new((value.into_inner() - self.into_inner()) / range)
+    }
+
+    /// [`inverse_lerp`](Self::inverse_lerp) clamped to `0..=1` and smoothed with
+    /// [`Easing::SmoothStep`].
+    pub fn smoothstep(self, to: Self, value: Self) -> Dimensionless<f64> {
+        UnitValue::new(Easing::SmoothStep.apply(self.inverse_lerp(to, value).into_inner()))
+    }
+}
+
+/// Curves that map `0..=1` onto `0..=1`, inputs outside that range are clamped.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Easing {
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    SmoothStep,
+    SmootherStep,
+}
+impl Easing {
+    pub fn apply(self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::QuadIn => t * t,
+            Easing::QuadOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Easing::QuadInOut if t < 0.5 => 2.0 * t * t,
+            Easing::QuadInOut => 1.0 - 2.0 * (1.0 - t) * (1.0 - t),
+            Easing::CubicIn => t * t * t,
+            Easing::CubicOut => 1.0 - (1.0 - t) * (1.0 - t) * (1.0 - t),
+            Easing::CubicInOut if t < 0.5 => 4.0 * t * t * t,
+            Easing::CubicInOut => 1.0 - 4.0 * (1.0 - t) * (1.0 - t) * (1.0 - t),
+            Easing::SmoothStep => t * t * (3.0 - 2.0 * t),
+            Easing::SmootherStep => t * t * t * (t * (6.0 * t - 15.0) + 10.0),
+        }
+    }
+}
+
+/// How a [`Track`] fills in between its keys.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum TrackInterpolation {
+    /// Holds each key's value until the next key.
+    Step,
+    /// Blends each pair of keys with the easing.
+    Eased(Easing),
+    /// A Catmull-Rom spline through the keys, parameterized by the key times.
+    CatmullRom,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Keyframe<V> {
+    pub time: Time<f64>,
+    pub value: V,
+}
+
+/// Values keyed by time, e.g. a camera path or a gauge animation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Track<V> {
+    keys: Vec<Keyframe<V>>,
+    interpolation: TrackInterpolation,
+}
+impl<V> Track<V>
+where
+    V: Interpolate,
+{
+    pub const fn new(interpolation: TrackInterpolation) -> Self {
+        Self {
+            keys: Vec::new(),
+            interpolation,
+        }
+    }
+
+    /// Keys sorted by time.
+    pub fn keys(&self) -> &[Keyframe<V>] {
+        &self.keys
+    }
+
+    pub const fn interpolation(&self) -> TrackInterpolation {
+        self.interpolation
+    }
+
+    /// Adds a key, replacing any key at the same time. Panics if `time` is NaN, which has no
+    /// place in the order.
+    pub fn insert(&mut self, time: Time<f64>, value: V) {
+        assert!(!time.value().is_nan(), "keyframe time is NaN");
+        let keyframe = Keyframe { time, value };
+        // Stored times are never NaN, so they always compare.
+        match self
+            .keys
+            .binary_search_by(|key| key.time.value().partial_cmp(time.value()).unwrap())
+        {
+            Ok(index) => self.keys[index] = keyframe,
+            Err(index) => self.keys.insert(index, keyframe),
+        }
+    }
+
+    pub fn with_key(mut self, time: Time<f64>, value: V) -> Self {
+        self.insert(time, value);
+        self
+    }
+
+    /// The value at `time`, holding the first and last keys outside of the track. [`None`] if
+    /// the track has no keys or `time` is NaN.
+    pub fn sample(&self, time: Time<f64>) -> Option<V> {
+        if time.value().is_nan() {
+            return None;
+        }
+        let first = self.keys.first()?;
+        let last = self.keys.last()?;
+        if time <= first.time {
+            return Some(first.value);
+        }
+        if time >= last.time {
+            return Some(last.value);
+        }
+        // The index of the first key after `time`, at least 1 and below `len`.
+        let next = self.keys.partition_point(|key| key.time <= time);
+        let from = self.keys[next - 1];
+        let to = self.keys[next];
+        let t = from.time.inverse_lerp(to.time, time).into_inner();
+        Some(match self.interpolation {
+            TrackInterpolation::Step => from.value,
+            TrackInterpolation::Eased(easing) => from.value.ease(to.value, t, easing),
+            TrackInterpolation::CatmullRom => {
+                // Missing outer keys are mirrored so the ends get a straight tangent.
+                let before = next
+                    .checked_sub(2)
+                    .map_or_else(|| mirror(to, from), |index| self.keys[index]);
+                let after = self
+                    .keys
+                    .get(next + 1)
+                    .copied()
+                    .unwrap_or_else(|| mirror(from, to));
+                catmull_rom([before, from, to, after], time)
+            }
+        })
+    }
+}
+
+fn mirror<V>(from: Keyframe<V>, to: Keyframe<V>) -> Keyframe<V>
+where
+    V: Interpolate,
+{
+    Keyframe {
+        time: to.time + (to.time - from.time),
+        value: from.value.lerp(to.value, 2.0),
+    }
+}
+
+/// Barry-Goldman's pyramid, `time` must lie between the middle two keys.
+fn catmull_rom<V>(keys: [Keyframe<V>; 4], time: Time<f64>) -> V
+where
+    V: Interpolate,
+{
+    let blend = |from: &Keyframe<V>, to: &Keyframe<V>, a: V, b: V| {
+        a.lerp(b, from.time.inverse_lerp(to.time, time).into_inner())
+    };
+    let [k0, k1, k2, k3] = &keys;
+    let a1 = blend(k0, k1, k0.value, k1.value);
+    let a2 = blend(k1, k2, k1.value, k2.value);
+    let a3 = blend(k2, k3, k2.value, k3.value);
+    let b1 = blend(k0, k2, a1, a2);
+    let b2 = blend(k1, k3, a2, a3);
+    blend(k1, k2, b1, b2)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::qty;
+    use crate::units::*;
+
+    fn seconds(value: f64) -> Time<f64> {
+        UnitValue::new(value * 1e9)
+    }
+
+    #[test]
+    fn lerp_test() {
+        let from: Length<f64> = qty!(1 km);
+        let to: Length<f64> = qty!(3 km);
+        assert_eq!(qty!(2 km), from.lerp(to, 0.5));
+        assert_eq!(qty!(5 km), from.lerp(to, 2.0));
+        assert_eq!(UnitValue::new(0.25), from.inverse_lerp(to, qty!(1.5 km)));
+        assert_eq!(UnitValue::new(0.0), from.inverse_lerp(from, to));
+        assert_eq!(UnitValue::new(1.0), from.smoothstep(to, qty!(4 km)));
+        assert_eq!(UnitValue::new(0.5), from.smoothstep(to, qty!(2 km)));
+
+        let start = UnitVector2::<f64, { UnitDims::LENGTH }>::new([0.0, 10.0]);
+        let end = UnitVector2::new([10.0, 0.0]);
+        assert_eq!([5.0, 5.0], start.lerp(end, 0.5).into_inner());
+        assert_eq!(
+            [0.625, 9.375],
+            start.ease(end, 0.25, Easing::QuadIn).into_inner()
+        );
+    }
+
+    #[test]
+    fn easing_test() {
+        for easing in [
+            Easing::Linear,
+            Easing::QuadIn,
+            Easing::QuadOut,
+            Easing::QuadInOut,
+            Easing::CubicIn,
+            Easing::CubicOut,
+            Easing::CubicInOut,
+            Easing::SmoothStep,
+            Easing::SmootherStep,
+        ] {
+            assert_eq!(0.0, easing.apply(0.0), "{:?}", easing);
+            assert_eq!(1.0, easing.apply(1.0), "{:?}", easing);
+            assert_eq!(1.0, easing.apply(2.0), "{:?}", easing);
+            let mut last = 0.0;
+            for step in 1..=100 {
+                let value = easing.apply(step as f64 / 100.0);
+                assert!(value >= last, "{:?} isn't monotonic", easing);
+                last = value;
+            }
+        }
+        assert_eq!(0.5, Easing::QuadInOut.apply(0.5));
+        assert_eq!(0.5, Easing::CubicInOut.apply(0.5));
+    }
+
+    #[test]
+    fn track_test() {
+        let track = Track::new(TrackInterpolation::Eased(Easing::Linear))
+            .with_key(seconds(2.0), Length::new(20.0))
+            .with_key(seconds(0.0), Length::new(0.0))
+            .with_key(seconds(1.0), Length::new(5.0));
+        assert_eq!(3, track.keys().len());
+        assert_eq!(seconds(0.0), track.keys()[0].time);
+        assert_eq!(Some(Length::new(0.0)), track.sample(seconds(-1.0)));
+        assert_eq!(Some(Length::new(2.5)), track.sample(seconds(0.5)));
+        assert_eq!(Some(Length::new(12.5)), track.sample(seconds(1.5)));
+        assert_eq!(Some(Length::new(20.0)), track.sample(seconds(3.0)));
+        assert_eq!(
+            None,
+            Track::<Length<f64>>::new(TrackInterpolation::Step).sample(seconds(0.0))
+        );
+
+        let mut step = Track::new(TrackInterpolation::Step)
+            .with_key(seconds(0.0), Length::new(1.0))
+            .with_key(seconds(1.0), Length::new(2.0));
+        assert_eq!(Some(Length::new(1.0)), step.sample(seconds(0.99)));
+        step.insert(seconds(0.0), Length::new(3.0));
+        assert_eq!(2, step.keys().len());
+        assert_eq!(Some(Length::new(3.0)), step.sample(seconds(0.5)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn nan_key_test() {
+        Track::new(TrackInterpolation::Step).insert(seconds(f64::NAN), Length::new(1.0));
+    }
+
+    #[test]
+    fn nan_sample_test() {
+        let track = Track::new(TrackInterpolation::CatmullRom)
+            .with_key(seconds(0.0), Length::new(0.0))
+            .with_key(seconds(1.0), Length::new(5.0));
+        assert_eq!(None, track.sample(seconds(f64::NAN)));
+    }
+
+    #[test]
+    fn catmull_rom_test() {
+        // Keys on a straight line at uneven times are followed exactly.
+        let line = Track::new(TrackInterpolation::CatmullRom)
+            .with_key(seconds(0.0), Length::new(0.0))
+            .with_key(seconds(1.0), Length::new(1.0))
+            .with_key(seconds(3.0), Length::new(3.0))
+            .with_key(seconds(4.0), Length::new(4.0));
+        for step in 0..=40 {
+            let time = step as f64 / 10.0;
+            let value = line.sample(seconds(time)).unwrap().into_inner();
+            assert!((value - time).abs() < 1e-9, "{} at {}", value, time);
+        }
+
+        // The spline passes through every key.
+        let path = Track::new(TrackInterpolation::CatmullRom)
+            .with_key(
+                seconds(0.0),
+                UnitVector2::<f64, { UnitDims::LENGTH }>::new([0.0, 0.0]),
+            )
+            .with_key(seconds(1.0), UnitVector2::new([1.0, 2.0]))
+            .with_key(seconds(2.0), UnitVector2::new([3.0, -1.0]));
+        for key in path.keys() {
+            assert_eq!(Some(key.value), path.sample(key.time));
+        }
+
+        // With only two keys the mirrored ends make it linear.
+        let pair = Track::new(TrackInterpolation::CatmullRom)
+            .with_key(seconds(0.0), Length::new(0.0))
+            .with_key(seconds(2.0), Length::new(4.0));
+        assert_eq!(Some(Length::new(1.0)), pair.sample(seconds(0.5)));
+    }
+}
@@ -0,0 +1,82 @@
+//! Kind tags for quantities that share dimensions, e.g. torque and energy.
+//!
+//! A [`UnitValue`](crate::units::UnitValue) carries a kind tag that defaults to [`Generic`].
+//! Values can only be added to or compared with values of the same kind, and are moved between
+//! kinds with [`with_kind`](crate::units::UnitValue::with_kind). Multiplying or dividing tagged
+//! values gives a [`Generic`] result.
+//!
+//! ```
+//! # #![feature(adt_const_params)]
+//! # #![allow(incomplete_features)]
+//! # use space_rpg::units::{Energy, Torque, UnitValue};
+//! let energy: Energy<f64> = UnitValue::new(1.0);
+//! let torque: Torque<f64> = energy.with_kind();
+//! let _ = torque + torque;
+//! ```
+//!
+//! Values of different kinds don't mix:
+//!
+//! ```compile_fail,E0277
+//! # #![feature(adt_const_params)]
+//! # #![allow(incomplete_features)]
+//! # use space_rpg::units::{Energy, Torque, UnitValue};
+//! let energy: Energy<f64> = UnitValue::new(1.0);
+//! let torque: Torque<f64> = UnitValue::new(1.0);
+//! let _ = energy + torque;
+//! ```
+//!
+//! and a kind only tags values with its [`DIMS`](KindTag::DIMS):
+//!
+//! ```compile_fail,E0080
+//! # #![feature(adt_const_params)]
+//! # #![allow(incomplete_features)]
+//! # use space_rpg::units::{kinds, Length, UnitValue};
+//! let length: Length<f64> = UnitValue::new(1.0);
+//! let _ = length.with_kind::<kinds::Torque>();
+//! ```
+
+use core::fmt::Debug;
+use core::hash::Hash;
+
+use crate::units::UnitDims;
+
+/// Marks a kind of quantity, implement this on an empty type to add a custom kind.
+pub trait KindTag: Copy + Debug + Hash + Ord + 'static {
+    const NAME: &'static str;
+    /// The dims of values of this kind, ignored by [`Generic`].
+    const DIMS: UnitDims;
+    /// Whether values of any dims may have this kind, only set by [`Generic`].
+    const ANY_DIMS: bool = false;
+}
+
+macro_rules! kind_tags {
+    ($($(#[$attr:meta])* $tag:ident => $name:literal, $dims:ident;)+) => {
+        $(
+            $(#[$attr])*
+            #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+            pub enum $tag {}
+            impl KindTag for $tag {
+                const NAME: &'static str = $name;
+                const DIMS: UnitDims = UnitDims::$dims;
+            }
+        )+
+    };
+}
+
+/// Any quantity, the default kind.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub enum Generic {}
+impl KindTag for Generic {
+    const NAME: &'static str = "generic";
+    const DIMS: UnitDims = UnitDims::DIMENSIONLESS;
+    const ANY_DIMS: bool = true;
+}
+
+kind_tags! {
+    /// Shares its dims with energy.
+    Torque => "torque", ENERGY;
+    /// Shares its dims with frequency.
+    Radioactivity => "radioactivity", FREQUENCY;
+    /// Shares its dims with frequency.
+    AngularVelocity => "angular velocity", FREQUENCY;
+}
@@ -0,0 +1,342 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::*;
+
+use crate::units::{UnitDims, UnitValue};
+
+/// A power level, e.g. in dBm or as an absolute bolometric magnitude.
+pub type PowerLevel = Level<{ UnitDims::POWER }>;
+/// An irradiance level, e.g. an apparent bolometric magnitude.
+pub type IrradianceLevel = Level<{ UnitDims::IRRADIANCE }>;
+
+/// How a logarithmic value is written down, all scales measure power ratios.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum LogScale {
+    /// `10 log10(P / P0)`.
+    Decibel,
+    /// `ln(P / P0) / 2`, the natural log of the amplitude ratio.
+    Neper,
+    /// `-2.5 log10(P / P0)`, brighter is smaller.
+    Magnitude,
+}
+impl LogScale {
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            LogScale::Decibel => "dB",
+            LogScale::Neper => "Np",
+            LogScale::Magnitude => "mag",
+        }
+    }
+
+    /// Converts a value in this scale to decibels.
+    pub fn to_decibels(self, value: f64) -> f64 {
+        match self {
+            LogScale::Decibel => value,
+            LogScale::Neper => value * 20.0 / core::f64::consts::LN_10,
+            LogScale::Magnitude => value * -4.0,
+        }
+    }
+
+    /// Converts decibels to a value in this scale.
+    pub fn from_decibels(self, decibels: f64) -> f64 {
+        match self {
+            LogScale::Decibel => decibels,
+            LogScale::Neper => decibels * core::f64::consts::LN_10 / 20.0,
+            LogScale::Magnitude => decibels / -4.0,
+        }
+    }
+}
+
+/// A dimensionless power ratio on a log scale, gains add.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Gain {
+    decibels: f64,
+}
+impl Gain {
+    pub const fn from_decibels(decibels: f64) -> Self {
+        Self { decibels }
+    }
+
+    pub fn new(value: f64, scale: LogScale) -> Self {
+        Self::from_decibels(scale.to_decibels(value))
+    }
+
+    pub fn from_nepers(nepers: f64) -> Self {
+        Self::new(nepers, LogScale::Neper)
+    }
+
+    pub fn from_power_ratio(ratio: f64) -> Self {
+        Self::from_decibels(10.0 * ratio.log10())
+    }
+
+    pub fn from_amplitude_ratio(ratio: f64) -> Self {
+        Self::from_decibels(20.0 * ratio.log10())
+    }
+
+    pub const fn decibels(self) -> f64 {
+        self.decibels
+    }
+
+    pub fn nepers(self) -> f64 {
+        self.in_scale(LogScale::Neper)
+    }
+
+    pub fn in_scale(self, scale: LogScale) -> f64 {
+        scale.from_decibels(self.decibels)
+    }
+
+    pub fn power_ratio(self) -> f64 {
+        10f64.powf(self.decibels / 10.0)
+    }
+
+    pub fn amplitude_ratio(self) -> f64 {
+        10f64.powf(self.decibels / 20.0)
+    }
+}
+impl fmt::Display for Gain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.decibels, f)?;
+        write!(f, " {}", LogScale::Decibel.symbol())
+    }
+}
+impl Neg for Gain {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::from_decibels(-self.decibels)
+    }
+}
+impl Add for Gain {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_decibels(self.decibels + rhs.decibels)
+    }
+}
+impl AddAssign for Gain {
+    fn add_assign(&mut self, rhs: Self) {
+        self.decibels += rhs.decibels;
+    }
+}
+impl Sub for Gain {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::from_decibels(self.decibels - rhs.decibels)
+    }
+}
+impl SubAssign for Gain {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.decibels -= rhs.decibels;
+    }
+}
+
+/// The zero of a [`Level`], a linear value in base units and how levels against it are written.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LevelReference<const DIMS: UnitDims> {
+    pub value: UnitValue<f64, DIMS>,
+    pub scale: LogScale,
+    pub symbol: &'static str,
+}
+impl LevelReference<{ UnitDims::POWER }> {
+    pub const MILLIWATT: Self = Self {
+        value: UnitValue::new(1e-6),
+        scale: LogScale::Decibel,
+        symbol: "dBm",
+    };
+    pub const WATT: Self = Self {
+        value: UnitValue::new(1e-3),
+        scale: LogScale::Decibel,
+        symbol: "dBW",
+    };
+    /// The IAU 2015 zero point for absolute bolometric magnitudes, 3.0128e28 W.
+    pub const ABSOLUTE_MAGNITUDE: Self = Self {
+        value: UnitValue::new(3.0128e25),
+        scale: LogScale::Magnitude,
+        symbol: "mag",
+    };
+}
+impl LevelReference<{ UnitDims::IRRADIANCE }> {
+    /// The IAU 2015 zero point for apparent bolometric magnitudes, 2.518021002e-8 W/m^2.
+    pub const APPARENT_MAGNITUDE: Self = Self {
+        value: UnitValue::new(2.518_021_002e-29),
+        scale: LogScale::Magnitude,
+        symbol: "mag",
+    };
+}
+
+/// A linear quantity on a log scale relative to a [`LevelReference`].
+///
+/// Adding a [`Gain`] shifts the level, adding two levels sums their powers and subtracting them
+/// gives the [`Gain`] between them.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Level<const DIMS: UnitDims> {
+    decibels: f64,
+    reference: LevelReference<DIMS>,
+}
+impl<const DIMS: UnitDims> Level<DIMS> {
+    /// `value` is in the scale of `reference`.
+    pub fn new(value: f64, reference: LevelReference<DIMS>) -> Self {
+        Self {
+            decibels: reference.scale.to_decibels(value),
+            reference,
+        }
+    }
+
+    pub fn from_linear(linear: UnitValue<f64, DIMS>, reference: LevelReference<DIMS>) -> Self {
+        Self {
+            decibels: Gain::from_power_ratio(linear.into_inner() / reference.value.into_inner())
+                .decibels(),
+            reference,
+        }
+    }
+
+    pub fn to_linear(&self) -> UnitValue<f64, DIMS> {
+        UnitValue::new(
+            self.reference.value.into_inner() * Gain::from_decibels(self.decibels).power_ratio(),
+        )
+    }
+
+    /// The level in the scale of its reference.
+    pub fn value(&self) -> f64 {
+        self.reference.scale.from_decibels(self.decibels)
+    }
+
+    pub const fn reference(&self) -> LevelReference<DIMS> {
+        self.reference
+    }
+
+    pub fn in_reference(self, reference: LevelReference<DIMS>) -> Self {
+        Self::from_linear(self.to_linear(), reference)
+    }
+}
+impl Level<{ UnitDims::POWER }> {
+    /// The irradiance at `distance` from an isotropic source.
+    pub fn irradiance_at(
+        &self,
+        distance: UnitValue<f64, { UnitDims::LENGTH }>,
+        reference: LevelReference<{ UnitDims::IRRADIANCE }>,
+    ) -> Level<{ UnitDims::IRRADIANCE }> {
+        let area = 4.0 * core::f64::consts::PI * distance.into_inner().powi(2);
+        Level::from_linear(
+            UnitValue::new(self.to_linear().into_inner() / area),
+            reference,
+        )
+    }
+}
+impl<const DIMS: UnitDims> PartialOrd for Level<DIMS> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.to_linear().partial_cmp(&other.to_linear())
+    }
+}
+impl<const DIMS: UnitDims> fmt::Display for Level<DIMS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value(), f)?;
+        write!(f, " {}", self.reference.symbol)
+    }
+}
+impl<const DIMS: UnitDims> Add<Gain> for Level<DIMS> {
+    type Output = Self;
+
+    fn add(self, rhs: Gain) -> Self::Output {
+        Self {
+            decibels: self.decibels + rhs.decibels,
+            reference: self.reference,
+        }
+    }
+}
+impl<const DIMS: UnitDims> Sub<Gain> for Level<DIMS> {
+    type Output = Self;
+
+    fn sub(self, rhs: Gain) -> Self::Output {
+        self + -rhs
+    }
+}
+/// Power sum, the result is against the reference of `self`.
+impl<const DIMS: UnitDims> Add for Level<DIMS> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_linear(self.to_linear() + rhs.to_linear(), self.reference)
+    }
+}
+impl<const DIMS: UnitDims> Sub for Level<DIMS> {
+    type Output = Gain;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Gain::from_decibels(self.in_reference(rhs.reference).decibels - rhs.decibels)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::qty;
+    use crate::units::*;
+
+    fn assert_close(expected: f64, actual: f64) {
+        assert!(
+            (expected - actual).abs() < 1e-9 * expected.abs().max(1.0),
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn gain_test() {
+        assert_close(100.0, Gain::from_decibels(20.0).power_ratio());
+        assert_close(10.0, Gain::from_decibels(20.0).amplitude_ratio());
+        assert_close(3.010_299_956_6, Gain::from_power_ratio(2.0).decibels());
+        assert_close(8.685_889_638, Gain::from_nepers(1.0).decibels());
+        assert_close(
+            1.0,
+            Gain::from_amplitude_ratio(core::f64::consts::E).nepers(),
+        );
+        assert_close(-4.0, Gain::new(1.0, LogScale::Magnitude).decibels());
+        assert_close(
+            -10.0,
+            (Gain::from_decibels(3.0) - Gain::from_decibels(13.0)).decibels(),
+        );
+        assert_eq!("3.0 dB", format!("{:.1}", Gain::from_decibels(3.0)));
+    }
+
+    #[test]
+    fn level_test() {
+        let transmitter = PowerLevel::new(30.0, LevelReference::MILLIWATT);
+        assert_close(0.0, transmitter.in_reference(LevelReference::WATT).value());
+        assert_close(
+            *qty!(1 kg m^2 s^-3).value(),
+            transmitter.to_linear().into_inner(),
+        );
+
+        let received = transmitter - Gain::from_decibels(110.0);
+        assert_eq!("-80 dBm", received.to_string());
+        assert_close(-110.0, (received - transmitter).decibels());
+
+        // Two equal incoherent sources are 3 dB louder, not twice the level.
+        let sum = received + received;
+        assert_close(-80.0 + 3.010_299_956_6, sum.value());
+        assert!(sum > received);
+    }
+
+    #[test]
+    fn magnitude_test() {
+        let sun = PowerLevel::from_linear(
+            qty!(3.828e26 kg m^2 s^-3),
+            LevelReference::ABSOLUTE_MAGNITUDE,
+        );
+        assert!((sun.value() - 4.74).abs() < 0.01, "{}", sun);
+
+        let from_earth = sun.irradiance_at(
+            UnitValue::new(constants::ASTRONOMICAL_UNIT.into_inner()),
+            LevelReference::APPARENT_MAGNITUDE,
+        );
+        assert!((from_earth.value() + 26.83).abs() < 0.01, "{}", from_earth);
+        // The solar constant, in W/m^2.
+        assert!((from_earth.to_linear().into_inner() * 1e21 - 1361.0).abs() < 1.0);
+
+        // 5 magnitudes fainter is 100 times less light.
+        let faint = from_earth + Gain::new(5.0, LogScale::Magnitude);
+        assert_close(0.01, (faint - from_earth).power_ratio());
+    }
+}
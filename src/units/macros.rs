@@ -0,0 +1,259 @@
+/// Defines a family of standard units for a single [`UnitDims`](crate::units::UnitDims).
+///
+/// The first entry is the base unit of the family, every following entry names its symbol
+/// and its size as a multiple of an earlier entry. Factors are decimal literals, the exact
+/// amount of base is built from them as an [`ExactValue`](crate::units::ExactValue). Amounts that
+/// fit are also kept in a `const` table of [`Factor`](crate::units::Factor)s, each exposed as a
+/// `const` such as `SECOND_FACTOR`:
+///
+/// ```ignore
+/// standard_unit_family! {
+///     pub enum Time: { UnitDims::TIME } {
+///         Nanosecond => "ns";
+///         Microsecond => "µs", Nanosecond * 1000;
+///         Second => "s", Microsecond * 1_000_000;
+///     }
+/// }
+/// ```
+///
+/// Naming a later entry fails to compile:
+///
+/// ```compile_fail,E0080
+/// # #![feature(adt_const_params)]
+/// # #![allow(incomplete_features)]
+/// # use space_rpg::standard_unit_family;
+/// # use space_rpg::units::*;
+/// standard_unit_family! {
+///     pub enum Time: { UnitDims::TIME } {
+///         Nanosecond => "ns";
+///         Second => "s", Microsecond * 1_000_000;
+///         Microsecond => "µs", Nanosecond * 1000;
+///     }
+/// }
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! standard_unit_family {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident: $dims:block {
+            $base:ident => $base_symbol:literal;
+            $($variant:ident => $symbol:literal, $relative_to:ident * $factor:literal;)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+        $vis enum $name {
+            $base,
+            $($variant,)*
+        }
+        // The factor table is filled in order, a later entry would still be a placeholder.
+        const _: () = {
+            $(
+                assert!(
+                    ($name::$relative_to as usize) < ($name::$variant as usize),
+                    concat!(stringify!($variant), " must be relative to an earlier unit")
+                );
+            )*
+        };
+        impl $name {
+            // `None` where the amount overflows a `Factor`.
+            const FACTORS: [Option<$crate::units::Factor>; [$base_symbol, $($symbol,)*].len()] = {
+                let mut factors =
+                    [Some($crate::units::Factor::ONE); [$base_symbol, $($symbol,)*].len()];
+                $(
+                    factors[$name::$variant as usize] = match (
+                        factors[$name::$relative_to as usize],
+                        $crate::units::Factor::checked_from_decimal(stringify!($factor)),
+                    ) {
+                        (Some(relative_to), Some(factor)) => relative_to.checked_mul(factor),
+                        _ => None,
+                    };
+                )*
+                factors
+            };
+
+            $crate::units::__private::paste! {
+                pub const [<$base:upper _FACTOR>]: $crate::units::Factor = $crate::units::Factor::ONE;
+                $(
+                    pub const [<$variant:upper _FACTOR>]: $crate::units::Factor =
+                        Self::$variant.amount_of_base();
+                )*
+            }
+
+            /// Panics if the amount doesn't fit in a [`Factor`](crate::units::Factor).
+            pub const fn amount_of_base(self) -> $crate::units::Factor {
+                match Self::FACTORS[self as usize] {
+                    Some(factor) => factor,
+                    None => panic!("Factor overflow!"),
+                }
+            }
+
+            pub const fn checked_amount_of_base(self) -> Option<$crate::units::Factor> {
+                Self::FACTORS[self as usize]
+            }
+        }
+        impl $crate::units::__private::Sealed for $name {}
+        impl $crate::units::StandardUnitImpl<$dims> for $name {
+            const BASE_UNIT: Self = Self::$base;
+            const UNITS: &'static [Self] = &[Self::$base, $(Self::$variant,)*];
+
+            fn symbol(self) -> &'static str {
+                match self {
+                    Self::$base => $base_symbol,
+                    $(Self::$variant => $symbol,)*
+                }
+            }
+
+            fn name(self) -> &'static str {
+                match self {
+                    Self::$base => stringify!($base),
+                    $(Self::$variant => stringify!($variant),)*
+                }
+            }
+
+            fn checked_amount_of_base(self) -> Option<$crate::units::Factor> {
+                Self::FACTORS[self as usize]
+            }
+
+            fn exact_amount_of_base(self) -> $crate::units::ExactValue {
+                match self {
+                    Self::$base => $crate::units::ExactValue::from_integer(1.into()),
+                    $(
+                        Self::$variant => {
+                            <Self as $crate::units::StandardUnitImpl<$dims>>::exact_amount_of_base(
+                                Self::$relative_to,
+                            ) * $crate::units::__private::exact_literal(stringify!($factor))
+                        }
+                    )*
+                }
+            }
+        }
+    };
+}
+
+/// Builds a [`UnitValue<f64, _>`](crate::units::UnitValue) in base units from a literal and
+/// unit symbols, e.g. `qty!(9.81 m/s^2)`, `qty!(3 kg m^2 s^-2)` or `qty!(-3 m/s)`.
+///
+/// Units are joined with `*`, `/` or whitespace and may carry an integer exponent with `^`.
+/// Unknown symbols fail to compile.
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # #![allow(incomplete_features)]
+/// # use space_rpg::qty;
+/// # use space_rpg::units::{Energy, Length, Velocity};
+/// let length: Length<f64> = qty!(2 km);
+/// assert_eq!(2e12, *length.value());
+/// let velocity: Velocity<f64> = qty!(-3 m/s);
+/// assert_eq!(-3.0, *velocity.value());
+/// let energy: Energy<f64> = qty!(4 kg m^2 s^-2);
+/// assert_eq!(4e6, *energy.value());
+/// ```
+#[macro_export]
+macro_rules! qty {
+    ($value:literal) => {
+        $crate::units::UnitValue::<f64, { $crate::units::UnitDims::DIMENSIONLESS }>::new($value as f64)
+    };
+    ($value:literal $($units:tt)+) => {
+        $crate::__qty!(@munch [($value as f64)] [$crate::units::UnitDims::DIMENSIONLESS] $($units)+)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __qty {
+    (@munch [$value:expr] [$dims:expr]) => {
+        $crate::units::UnitValue::<f64, { $dims }>::new($value)
+    };
+    (@munch [$value:expr] [$dims:expr] $unit:ident $($rest:tt)*) => {
+        $crate::__qty!(@munch [$value] [$dims] * $unit $($rest)*)
+    };
+    (@munch [$value:expr] [$dims:expr] * $unit:ident ^ - $exp:literal $($rest:tt)*) => {
+        $crate::__qty!(@apply [$value] [$dims] $unit (-$exp) $($rest)*)
+    };
+    (@munch [$value:expr] [$dims:expr] * $unit:ident ^ $exp:literal $($rest:tt)*) => {
+        $crate::__qty!(@apply [$value] [$dims] $unit ($exp) $($rest)*)
+    };
+    (@munch [$value:expr] [$dims:expr] * $unit:ident $($rest:tt)*) => {
+        $crate::__qty!(@apply [$value] [$dims] $unit (1) $($rest)*)
+    };
+    (@munch [$value:expr] [$dims:expr] / $unit:ident ^ - $exp:literal $($rest:tt)*) => {
+        $crate::__qty!(@apply [$value] [$dims] $unit ($exp) $($rest)*)
+    };
+    (@munch [$value:expr] [$dims:expr] / $unit:ident ^ $exp:literal $($rest:tt)*) => {
+        $crate::__qty!(@apply [$value] [$dims] $unit (-$exp) $($rest)*)
+    };
+    (@munch [$value:expr] [$dims:expr] / $unit:ident $($rest:tt)*) => {
+        $crate::__qty!(@apply [$value] [$dims] $unit (-1) $($rest)*)
+    };
+    (@apply [$value:expr] [$dims:expr] $unit:ident ($exp:expr) $($rest:tt)*) => {
+        $crate::__qty!(@munch
+            [$value * $crate::units::__private::powi($crate::units::__private::factor_of($crate::__qty_unit!($unit)), $exp)]
+            [$dims.add($crate::units::__private::dims_of($crate::__qty_unit!($unit)).mul($exp))]
+            $($rest)*
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __qty_unit {
+    (ns) => { $crate::units::TimeUnit::Nanosecond };
+    (us) => { $crate::units::TimeUnit::Microsecond };
+    (ms) => { $crate::units::TimeUnit::Millisecond };
+    (s) => { $crate::units::TimeUnit::Second };
+    (min) => { $crate::units::TimeUnit::Minute };
+    (h) => { $crate::units::TimeUnit::Hour };
+    (GSd) => { $crate::units::TimeUnit::GSDay };
+    (GSmo) => { $crate::units::TimeUnit::GSMonth };
+    (GSyr) => { $crate::units::TimeUnit::GSYear };
+    (GScen) => { $crate::units::TimeUnit::GSCentury };
+    (GSmil) => { $crate::units::TimeUnit::GSMillennium };
+    (a) => { $crate::units::TimeUnit::JulianYear };
+    (sd) => { $crate::units::TimeUnit::SiderealDay };
+    (wk) => { $crate::units::TimeUnit::Week };
+
+    (nm) => { $crate::units::LengthUnit::NanoMeter };
+    (um) => { $crate::units::LengthUnit::MicroMeter };
+    (mm) => { $crate::units::LengthUnit::MilliMeter };
+    (cm) => { $crate::units::LengthUnit::Centimeter };
+    (dm) => { $crate::units::LengthUnit::Decimeter };
+    (m) => { $crate::units::LengthUnit::Meter };
+    (km) => { $crate::units::LengthUnit::Kilometer };
+    (au) => { $crate::units::LengthUnit::AU };
+    (AU) => { $crate::units::LengthUnit::AU };
+    (ly) => { $crate::units::LengthUnit::LightYear };
+    (pc) => { $crate::units::LengthUnit::Parsec };
+    (kpc) => { $crate::units::LengthUnit::Kiloparsec };
+    (Mm) => { $crate::units::LengthUnit::Megameter };
+    (SolarRadius) => { $crate::units::LengthUnit::SolarRadius };
+    (EarthRadius) => { $crate::units::LengthUnit::EarthRadius };
+    (LD) => { $crate::units::LengthUnit::LunarDistance };
+    (ft) => { $crate::units::LengthUnit::Foot };
+    (mi) => { $crate::units::LengthUnit::Mile };
+    (nmi) => { $crate::units::LengthUnit::NauticalMile };
+
+    (mg) => { $crate::units::MassUnit::Milligram };
+    (g) => { $crate::units::MassUnit::Gram };
+    (kg) => { $crate::units::MassUnit::Kilogram };
+    (t) => { $crate::units::MassUnit::Tonne };
+    (oz) => { $crate::units::MassUnit::Oz };
+    (lb) => { $crate::units::MassUnit::Pound };
+    (ton) => { $crate::units::MassUnit::Ton };
+    (EarthMass) => { $crate::units::MassUnit::EarthMass };
+    (SolarMass) => { $crate::units::MassUnit::SolarMass };
+    (JupiterMass) => { $crate::units::MassUnit::JupiterMass };
+    (LunarMass) => { $crate::units::MassUnit::LunarMass };
+    (u) => { $crate::units::MassUnit::AtomicMassUnit };
+
+    (pA) => { $crate::units::ElectricCurrentUnit::Picoampere };
+    (nA) => { $crate::units::ElectricCurrentUnit::Nanoampere };
+    (uA) => { $crate::units::ElectricCurrentUnit::Microampere };
+    (mA) => { $crate::units::ElectricCurrentUnit::Milliampere };
+    (A) => { $crate::units::ElectricCurrentUnit::Ampere };
+
+    ($unknown:ident) => {
+        compile_error!(concat!("unknown unit symbol `", stringify!($unknown), "`"))
+    };
+}
@@ -1,20 +1,85 @@
-use std::num::NonZeroIsize;
-
-use f128::f128;
-use lazy_static::lazy_static;
+use alloc::vec::Vec;
+use core::num::NonZeroIsize;
+use core::str::FromStr;
 
+pub use display_value::*;
+pub use duration::*;
+pub use error::*;
+pub use exact_value::*;
+pub use expression::*;
+pub use factor::*;
+#[cfg(feature = "std")]
+pub use game_clock::*;
+pub use gs_date_time::*;
+#[cfg(feature = "std")]
+pub use integrate::*;
+pub use interpolate::*;
+pub use kinds::{Generic, KindTag};
+#[cfg(feature = "std")]
+pub use logarithmic::*;
+pub use prefix::*;
+pub use quantity::*;
+pub use quantity_kind::*;
+#[cfg(feature = "rand")]
+pub use random::*;
+#[cfg(feature = "scripting")]
+pub use scripting::*;
+pub use table::*;
 pub use unit_dims::*;
+pub use unit_matrix::*;
+pub use unit_point::*;
 pub use unit_value::*;
+pub use unit_vector::*;
 pub use value::*;
 
 use crate::units::sealed::Sealed;
 
+pub mod constants;
+pub mod kinds;
+mod display_value;
+mod duration;
+mod error;
+mod exact_value;
+mod expression;
+mod factor;
+#[cfg(feature = "std")]
+mod game_clock;
+mod gs_date_time;
+#[cfg(feature = "std")]
+mod integrate;
+mod interpolate;
+#[cfg(feature = "std")]
+mod logarithmic;
+mod macros;
+mod prefix;
+mod quantity;
+mod quantity_kind;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "scripting")]
+mod scripting;
+mod table;
 mod unit_dims;
+mod unit_matrix;
+mod unit_point;
 mod unit_value;
+mod unit_vector;
 mod value;
 
 pub type DimsType = isize;
-pub type DisplayValue = f128;
+#[cfg(all(feature = "f128", feature = "decimal"))]
+compile_error!(
+    "the `f128` and `decimal` backends are mutually exclusive, use `default-features = false` \
+     to pick `decimal`"
+);
+#[cfg(all(feature = "f64", any(feature = "f128", feature = "decimal")))]
+compile_error!("the `f64` backend can't be combined with `f128` or `decimal`");
+#[cfg(feature = "f128")]
+pub type DisplayValue = f128::f128;
+#[cfg(all(feature = "decimal", not(feature = "f128")))]
+pub type DisplayValue = bigdecimal::BigDecimal;
+#[cfg(not(any(feature = "f128", feature = "decimal")))]
+pub type DisplayValue = f64;
 
 pub type TimeUnit = time::Time;
 pub type MassUnit = mass::Mass;
@@ -35,7 +100,9 @@ impl CompositeUnit {
     }
 }
 
-#[derive(Copy, Clone, Debug)]
+/// `Copy` unless the `decimal` backend is enabled.
+#[cfg_attr(not(feature = "decimal"), derive(Copy))]
+#[derive(Clone, Debug)]
 pub enum Unit {
     Standard(StandardUnit),
     Custom {
@@ -51,7 +118,7 @@ impl Unit {
         }
     }
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub enum StandardUnit {
     Time(TimeUnit),
     Mass(MassUnit),
@@ -59,6 +126,25 @@ pub enum StandardUnit {
     ElectricCurrent(ElectricCurrentUnit),
 }
 impl StandardUnit {
+    pub fn all() -> impl Iterator<Item = Self> {
+        TimeUnit::UNITS
+            .iter()
+            .copied()
+            .map(StandardUnit::Time)
+            .chain(MassUnit::UNITS.iter().copied().map(StandardUnit::Mass))
+            .chain(LengthUnit::UNITS.iter().copied().map(StandardUnit::Length))
+            .chain(
+                ElectricCurrentUnit::UNITS
+                    .iter()
+                    .copied()
+                    .map(StandardUnit::ElectricCurrent),
+            )
+    }
+
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        Self::all().find(|unit| unit.symbol() == symbol)
+    }
+
     pub fn dims(&self) -> UnitDims {
         match self {
             StandardUnit::Time(_) => TimeUnit::DIMS,
@@ -67,24 +153,131 @@ impl StandardUnit {
             StandardUnit::ElectricCurrent(_) => ElectricCurrentUnit::DIMS,
         }
     }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            StandardUnit::Time(unit) => unit.symbol(),
+            StandardUnit::Mass(unit) => unit.symbol(),
+            StandardUnit::Length(unit) => unit.symbol(),
+            StandardUnit::ElectricCurrent(unit) => unit.symbol(),
+        }
+    }
+
+    pub fn family(&self) -> &'static str {
+        match self {
+            StandardUnit::Time(_) => "time",
+            StandardUnit::Mass(_) => "mass",
+            StandardUnit::Length(_) => "length",
+            StandardUnit::ElectricCurrent(_) => "electric_current",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            StandardUnit::Time(unit) => unit.name(),
+            StandardUnit::Mass(unit) => unit.name(),
+            StandardUnit::Length(unit) => unit.name(),
+            StandardUnit::ElectricCurrent(unit) => unit.name(),
+        }
+    }
+
+    /// Panics if the amount doesn't fit in a [`Factor`].
+    pub fn amount_of_base(&self) -> Factor {
+        self.checked_amount_of_base().expect("Factor overflow!")
+    }
+
+    pub fn checked_amount_of_base(&self) -> Option<Factor> {
+        match self {
+            StandardUnit::Time(unit) => unit.checked_amount_of_base(),
+            StandardUnit::Mass(unit) => unit.checked_amount_of_base(),
+            StandardUnit::Length(unit) => unit.checked_amount_of_base(),
+            StandardUnit::ElectricCurrent(unit) => unit.checked_amount_of_base(),
+        }
+    }
+
+    /// [`UnitError::Overflow`] if the amount doesn't fit in a [`Factor`].
+    pub fn try_amount_of_base(&self) -> Result<Factor, UnitError> {
+        self.checked_amount_of_base().ok_or(UnitError::Overflow)
+    }
+
+    pub fn exact_amount_of_base(&self) -> ExactValue {
+        match self {
+            StandardUnit::Time(unit) => unit.exact_amount_of_base(),
+            StandardUnit::Mass(unit) => unit.exact_amount_of_base(),
+            StandardUnit::Length(unit) => unit.exact_amount_of_base(),
+            StandardUnit::ElectricCurrent(unit) => unit.exact_amount_of_base(),
+        }
+    }
 }
 
-pub trait StandardUnitImpl<const DIMS: UnitDims>: Copy + Sealed{
+impl FromStr for StandardUnit {
+    type Err = UnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_symbol(s).ok_or_else(|| UnitError::UnknownUnit(s.into()))
+    }
+}
+
+pub trait StandardUnitImpl<const DIMS: UnitDims>: Copy + Sealed + 'static {
     const DIMS: UnitDims = DIMS;
     const BASE_UNIT: Self;
+    const UNITS: &'static [Self];
 
-    fn amount_of_base(self) -> DisplayValue;
+    fn symbol(self) -> &'static str;
+    fn name(self) -> &'static str;
+    /// [`None`] if the amount doesn't fit in a [`Factor`].
+    fn checked_amount_of_base(self) -> Option<Factor>;
+    /// Panics if the amount doesn't fit in a [`Factor`].
+    fn amount_of_base(self) -> Factor {
+        self.checked_amount_of_base().expect("Factor overflow!")
+    }
+    /// [`UnitError::Overflow`] if the amount doesn't fit in a [`Factor`].
+    fn try_amount_of_base(self) -> Result<Factor, UnitError> {
+        self.checked_amount_of_base().ok_or(UnitError::Overflow)
+    }
+    fn amount_of_base_as<D>(self) -> D
+    where
+        D: DisplayNumber,
+    {
+        match self.checked_amount_of_base() {
+            Some(factor) => D::from_factor(factor),
+            None => D::from_exact(&self.exact_amount_of_base()),
+        }
+    }
+    /// Built from the decimal literals of the family, so it's exact however large it gets.
+    fn exact_amount_of_base(self) -> ExactValue;
+    fn exact_base_repr(self, display_value: &ExactValue) -> ExactValue {
+        display_value * self.exact_amount_of_base()
+    }
+    fn exact_display_repr(self, base_value: &ExactValue) -> ExactValue {
+        base_value / self.exact_amount_of_base()
+    }
     fn base_repr<T>(self, display_value: DisplayValue) -> T
     where
         T: From<DisplayValue>,
     {
-        (display_value * self.amount_of_base()).into()
+        self.base_repr_as(display_value)
     }
     fn display_repr<T>(self, base_value: T) -> DisplayValue
     where
         DisplayValue: From<T>,
     {
-        DisplayValue::from(base_value) / self.amount_of_base()
+        self.display_repr_as(base_value)
+    }
+    /// Like [`base_repr`](Self::base_repr) with any [`DisplayNumber`] backend.
+    fn base_repr_as<D, T>(self, display_value: D) -> T
+    where
+        D: DisplayNumber,
+        T: From<D>,
+    {
+        (display_value * self.amount_of_base_as()).into()
+    }
+    /// Like [`display_repr`](Self::display_repr) with any [`DisplayNumber`] backend.
+    fn display_repr_as<D, T>(self, base_value: T) -> D
+    where
+        D: DisplayNumber + From<T>,
+    {
+        D::from(base_value) / self.amount_of_base_as()
     }
 }
 
@@ -92,181 +285,126 @@ mod sealed {
     pub trait Sealed{}
 }
 
-mod time {
-    use super::*;
+#[doc(hidden)]
+pub mod __private {
+    pub use paste::paste;
 
-    lazy_static!{
-        static ref NANOSECOND_FACTOR: DisplayValue = f128::from(1);
-        static ref MICROSECOND_FACTOR: DisplayValue = f128::from(1000) * *NANOSECOND_FACTOR;
-        static ref MILLISECOND_FACTOR: DisplayValue = f128::from(1000) * *MICROSECOND_FACTOR;
-        static ref SECOND_FACTOR: DisplayValue = f128::from(1000) * *MILLISECOND_FACTOR;
-        static ref MINUTE_FACTOR: DisplayValue = f128::from(60) * *SECOND_FACTOR;
-        static ref HOUR_FACTOR: DisplayValue = f128::from(60) * *MINUTE_FACTOR;
-        static ref GS_DAY_FACTOR: DisplayValue = f128::from(24) * *HOUR_FACTOR;
-        static ref GS_MONTH_FACTOR: DisplayValue = f128::from(30) * *GS_DAY_FACTOR;
-        static ref GS_YEAR_FACTOR: DisplayValue = f128::from(12) * *GS_MONTH_FACTOR;
-        static ref GS_CENTURY_FACTOR: DisplayValue = f128::from(100) * *GS_YEAR_FACTOR;
-        static ref GS_MILLENNIUM_FACTOR: DisplayValue = f128::from(1000) * *GS_YEAR_FACTOR;
+    pub use super::sealed::Sealed;
+    use super::{parse_exact, ExactValue, StandardUnitImpl, UnitDims};
+
+    /// A factor literal from [`standard_unit_family!`](crate::standard_unit_family), which may
+    /// contain `_`.
+    pub fn exact_literal(text: &str) -> ExactValue {
+        parse_exact(&text.replace('_', "")).expect("Invalid factor literal!")
+    }
+
+    pub const fn dims_of<U, const DIMS: UnitDims>(_unit: U) -> UnitDims
+    where
+        U: StandardUnitImpl<DIMS>,
+    {
+        DIMS
     }
 
-    #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
-    pub enum Time {
-        Nanosecond,
-        Microsecond,
-        Millisecond,
-        Second,
-        Minute,
-        Hour,
-        GSDay,
-        GSMonth,
-        GSYear,
-        GSCentury,
-        GSMillennium,
+    pub fn factor_of<U, const DIMS: UnitDims>(unit: U) -> f64
+    where
+        U: StandardUnitImpl<DIMS>,
+    {
+        unit.amount_of_base_as()
     }
-    impl Sealed for Time{}
-    impl StandardUnitImpl<{ UnitDims::TIME }> for Time {
-        const BASE_UNIT: Self = Self::Nanosecond;
-
-        fn amount_of_base(self) -> DisplayValue {
-            match self {
-                Time::Nanosecond => *NANOSECOND_FACTOR,
-                Time::Microsecond => *MICROSECOND_FACTOR,
-                Time::Millisecond => *MILLISECOND_FACTOR,
-                Time::Second => *SECOND_FACTOR,
-                Time::Minute => *MINUTE_FACTOR,
-                Time::Hour => *HOUR_FACTOR,
-                Time::GSDay => *GS_DAY_FACTOR,
-                Time::GSMonth => *GS_MONTH_FACTOR,
-                Time::GSYear => *GS_YEAR_FACTOR,
-                Time::GSCentury => *GS_CENTURY_FACTOR,
-                Time::GSMillennium => *GS_MILLENNIUM_FACTOR,
-            }
+
+    // `f64::powi` needs std.
+    pub fn powi(base: f64, exponent: i32) -> f64 {
+        let mut out = 1.0;
+        for _ in 0..exponent.unsigned_abs() {
+            out *= base;
+        }
+        if exponent < 0 {
+            1.0 / out
+        } else {
+            out
         }
     }
 }
-mod length {
+
+mod time {
     use super::*;
 
-    lazy_static!{
-        static ref NANOMETER_FACTOR: DisplayValue = f128::from(1);
-        static ref MICROMETER_FACTOR: DisplayValue = f128::from(1000) * *NANOMETER_FACTOR;
-        static ref MILLIMETER_FACTOR: DisplayValue = f128::from(1000) * *MICROMETER_FACTOR;
-        static ref CENTIMETER_FACTOR: DisplayValue = f128::from(10) * *MILLIMETER_FACTOR;
-        static ref DECIMETER_FACTOR: DisplayValue = f128::from(100) * *MILLIMETER_FACTOR;
-        static ref METER_FACTOR: DisplayValue = f128::from(1000) * *MILLIMETER_FACTOR;
-        static ref KILOMETER_FACTOR: DisplayValue = f128::from(1000) * *METER_FACTOR;
-        static ref AU_FACTOR: DisplayValue = f128::from(1.496e+11) * *METER_FACTOR;
-        static ref LIGHT_YEAR_FACTOR: DisplayValue = f128::from(9.461e+15) * *METER_FACTOR;
+    crate::standard_unit_family! {
+        pub enum Time: { UnitDims::TIME } {
+            Nanosecond => "ns";
+            Microsecond => "µs", Nanosecond * 1000;
+            Millisecond => "ms", Microsecond * 1000;
+            Second => "s", Millisecond * 1000;
+            Minute => "min", Second * 60;
+            Hour => "h", Minute * 60;
+            GSDay => "GSd", Hour * 24;
+            GSMonth => "GSmo", GSDay * 30;
+            GSYear => "GSyr", GSMonth * 12;
+            GSCentury => "GScen", GSYear * 100;
+            GSMillennium => "GSmil", GSYear * 1000;
+            JulianYear => "a", Second * 31_557_600;
+            SiderealDay => "sd", Millisecond * 86_164_090.5;
+            Week => "wk", Hour * 168;
+        }
     }
+}
+mod length {
+    use super::*;
 
-    #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
-    pub enum Length {
-        NanoMeter,
-        MicroMeter,
-        MilliMeter,
-        Centimeter,
-        Decimeter,
-        Meter,
-        Kilometer,
-        AU,
-        LightYear,
-    }
-    impl Sealed for Length{}
-    impl StandardUnitImpl<{ UnitDims::LENGTH }> for Length {
-        const BASE_UNIT: Self = Self::NanoMeter;
-
-        fn amount_of_base(self) -> DisplayValue {
-            match self {
-                Length::NanoMeter => *NANOMETER_FACTOR,
-                Length::MicroMeter => *MICROMETER_FACTOR,
-                Length::MilliMeter => *MILLIMETER_FACTOR,
-                Length::Centimeter => *CENTIMETER_FACTOR,
-                Length::Decimeter => *DECIMETER_FACTOR,
-                Length::Meter => *METER_FACTOR,
-                Length::Kilometer => *KILOMETER_FACTOR,
-                Length::AU => *AU_FACTOR,
-                Length::LightYear => *LIGHT_YEAR_FACTOR,
-            }
+    crate::standard_unit_family! {
+        pub enum Length: { UnitDims::LENGTH } {
+            NanoMeter => "nm";
+            MicroMeter => "µm", NanoMeter * 1000;
+            MilliMeter => "mm", MicroMeter * 1000;
+            Centimeter => "cm", MilliMeter * 10;
+            Decimeter => "dm", MilliMeter * 100;
+            Meter => "m", MilliMeter * 1000;
+            Kilometer => "km", Meter * 1000;
+            AU => "au", Meter * 149_597_870_700;
+            LightYear => "ly", Meter * 9_460_730_472_580_800;
+            Parsec => "pc", Meter * 30_856_775_814_913_673;
+            Kiloparsec => "kpc", Parsec * 1000;
+            Megameter => "Mm", Kilometer * 1000;
+            SolarRadius => "R☉", Kilometer * 695_700;
+            EarthRadius => "R⊕", Meter * 6_378_100;
+            LunarDistance => "LD", Kilometer * 384_399;
+            Foot => "ft", MilliMeter * 304.8;
+            Mile => "mi", Foot * 5280;
+            NauticalMile => "nmi", Meter * 1852;
         }
     }
 }
 mod mass {
     use super::*;
 
-    lazy_static! {
-        static ref MILLIGRAM_FACTOR: DisplayValue = f128::from(1);
-        static ref GRAM_FACTOR: DisplayValue = f128::from(1000) * *MILLIGRAM_FACTOR;
-        static ref KILOGRAM_FACTOR: DisplayValue = f128::from(1000) * *GRAM_FACTOR;
-        static ref TONNE_FACTOR: DisplayValue = f128::from(1000) * *KILOGRAM_FACTOR;
-        static ref OZ_FACTOR: DisplayValue = f128::from(28.3495) * *GRAM_FACTOR;
-        static ref POUND_FACTOR: DisplayValue = f128::from(453.59237) * *GRAM_FACTOR;
-        static ref TON_FACTOR: DisplayValue = f128::from(2000) * *POUND_FACTOR;
-        static ref EARTH_MASS_FACTOR: DisplayValue = f128::from(5.9722e+24) * *KILOGRAM_FACTOR;
-        static ref SOLAR_MASS_FACTOR: DisplayValue = f128::from(1.989e+30) * *KILOGRAM_FACTOR;
-    }
-    #[allow(clippy::enum_variant_names)]
-    #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
-    pub enum Mass {
-        Milligram,
-        Gram,
-        Kilogram,
-        Tonne,
-        Oz,
-        Pound,
-        Ton,
-        EarthMass,
-        SolarMass,
-    }
-    impl Sealed for Mass{}
-    impl StandardUnitImpl<{ UnitDims::MASS }> for Mass {
-        const BASE_UNIT: Self = Self::Milligram;
-
-        fn amount_of_base(self) -> DisplayValue {
-            match self {
-                Mass::Milligram => *MILLIGRAM_FACTOR,
-                Mass::Gram => *GRAM_FACTOR,
-                Mass::Kilogram => *KILOGRAM_FACTOR,
-                Mass::Tonne => *TONNE_FACTOR,
-                Mass::Oz => *OZ_FACTOR,
-                Mass::Pound => *POUND_FACTOR,
-                Mass::Ton => *TON_FACTOR,
-                Mass::EarthMass => *EARTH_MASS_FACTOR,
-                Mass::SolarMass => *SOLAR_MASS_FACTOR,
-            }
+    crate::standard_unit_family! {
+        #[allow(clippy::enum_variant_names)]
+        pub enum Mass: { UnitDims::MASS } {
+            Milligram => "mg";
+            Gram => "g", Milligram * 1000;
+            Kilogram => "kg", Gram * 1000;
+            Tonne => "t", Kilogram * 1000;
+            Oz => "oz", Gram * 28.3495;
+            Pound => "lb", Gram * 453.59237;
+            Ton => "ton", Pound * 2000;
+            EarthMass => "M⊕", Kilogram * 5.9722e24;
+            SolarMass => "M☉", Kilogram * 1.98847e30;
+            JupiterMass => "M♃", Kilogram * 1.89813e27;
+            LunarMass => "M☾", Kilogram * 7.346e22;
+            AtomicMassUnit => "u", Kilogram * 1.660_539_066_60e-27;
         }
     }
 }
 mod electric_current {
     use super::*;
 
-    lazy_static! {
-        static ref PICOAMPERE_FACTOR: DisplayValue = f128::from(1);
-        static ref NANOAMPERE_FACTOR: DisplayValue = f128::from(1000) * *PICOAMPERE_FACTOR;
-        static ref MICROAMPERE_FACTOR: DisplayValue = f128::from(1000) * *NANOAMPERE_FACTOR;
-        static ref MILLIAMPERE_FACTOR: DisplayValue = f128::from(1000) * *MICROAMPERE_FACTOR;
-        static ref AMPERE_FACTOR: DisplayValue = f128::from(1000) * *MILLIAMPERE_FACTOR;
-    }
-
-    #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
-    pub enum ElectricCurrent {
-        Picoampere,
-        Nanoampere,
-        Microampere,
-        Milliampere,
-        Ampere,
-    }
-    impl Sealed for ElectricCurrent{}
-    impl StandardUnitImpl<{ UnitDims::ELECTRIC_CURRENT }> for ElectricCurrent {
-        const BASE_UNIT: Self = Self::Picoampere;
-
-        fn amount_of_base(self) -> DisplayValue {
-            match self {
-                ElectricCurrent::Picoampere => *PICOAMPERE_FACTOR,
-                ElectricCurrent::Nanoampere => *NANOAMPERE_FACTOR,
-                ElectricCurrent::Microampere => *MICROAMPERE_FACTOR,
-                ElectricCurrent::Milliampere => *MILLIAMPERE_FACTOR,
-                ElectricCurrent::Ampere => *AMPERE_FACTOR,
-            }
+    crate::standard_unit_family! {
+        pub enum ElectricCurrent: { UnitDims::ELECTRIC_CURRENT } {
+            Picoampere => "pA";
+            Nanoampere => "nA", Picoampere * 1000;
+            Microampere => "µA", Nanoampere * 1000;
+            Milliampere => "mA", Microampere * 1000;
+            Ampere => "A", Milliampere * 1000;
         }
     }
 }
@@ -0,0 +1,415 @@
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
+
+use crate::units::{DisplayNumber, ExactValue, Factor, UnitDims, UnitRegistry, UnitTableEntry};
+
+/// A SI (quecto through quetta) or IEC binary (kibi through yobi) prefix.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Prefix {
+    Quecto,
+    Ronto,
+    Yocto,
+    Zepto,
+    Atto,
+    Femto,
+    Pico,
+    Nano,
+    Micro,
+    Milli,
+    Centi,
+    Deci,
+    Deca,
+    Hecto,
+    Kilo,
+    Mega,
+    Giga,
+    Tera,
+    Peta,
+    Exa,
+    Zetta,
+    Yotta,
+    Ronna,
+    Quetta,
+    Kibi,
+    Mebi,
+    Gibi,
+    Tebi,
+    Pebi,
+    Exbi,
+    Zebi,
+    Yobi,
+}
+impl Prefix {
+    pub const DECIMAL: &'static [Self] = &[
+        Prefix::Quecto,
+        Prefix::Ronto,
+        Prefix::Yocto,
+        Prefix::Zepto,
+        Prefix::Atto,
+        Prefix::Femto,
+        Prefix::Pico,
+        Prefix::Nano,
+        Prefix::Micro,
+        Prefix::Milli,
+        Prefix::Centi,
+        Prefix::Deci,
+        Prefix::Deca,
+        Prefix::Hecto,
+        Prefix::Kilo,
+        Prefix::Mega,
+        Prefix::Giga,
+        Prefix::Tera,
+        Prefix::Peta,
+        Prefix::Exa,
+        Prefix::Zetta,
+        Prefix::Yotta,
+        Prefix::Ronna,
+        Prefix::Quetta,
+    ];
+    pub const BINARY: &'static [Self] = &[
+        Prefix::Kibi,
+        Prefix::Mebi,
+        Prefix::Gibi,
+        Prefix::Tebi,
+        Prefix::Pebi,
+        Prefix::Exbi,
+        Prefix::Zebi,
+        Prefix::Yobi,
+    ];
+
+    pub fn all() -> impl Iterator<Item = Self> {
+        Self::DECIMAL.iter().chain(Self::BINARY).copied()
+    }
+
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Prefix::Quecto => "q",
+            Prefix::Ronto => "r",
+            Prefix::Yocto => "y",
+            Prefix::Zepto => "z",
+            Prefix::Atto => "a",
+            Prefix::Femto => "f",
+            Prefix::Pico => "p",
+            Prefix::Nano => "n",
+            Prefix::Micro => "µ",
+            Prefix::Milli => "m",
+            Prefix::Centi => "c",
+            Prefix::Deci => "d",
+            Prefix::Deca => "da",
+            Prefix::Hecto => "h",
+            Prefix::Kilo => "k",
+            Prefix::Mega => "M",
+            Prefix::Giga => "G",
+            Prefix::Tera => "T",
+            Prefix::Peta => "P",
+            Prefix::Exa => "E",
+            Prefix::Zetta => "Z",
+            Prefix::Yotta => "Y",
+            Prefix::Ronna => "R",
+            Prefix::Quetta => "Q",
+            Prefix::Kibi => "Ki",
+            Prefix::Mebi => "Mi",
+            Prefix::Gibi => "Gi",
+            Prefix::Tebi => "Ti",
+            Prefix::Pebi => "Pi",
+            Prefix::Exbi => "Ei",
+            Prefix::Zebi => "Zi",
+            Prefix::Yobi => "Yi",
+        }
+    }
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Prefix::Quecto => "Quecto",
+            Prefix::Ronto => "Ronto",
+            Prefix::Yocto => "Yocto",
+            Prefix::Zepto => "Zepto",
+            Prefix::Atto => "Atto",
+            Prefix::Femto => "Femto",
+            Prefix::Pico => "Pico",
+            Prefix::Nano => "Nano",
+            Prefix::Micro => "Micro",
+            Prefix::Milli => "Milli",
+            Prefix::Centi => "Centi",
+            Prefix::Deci => "Deci",
+            Prefix::Deca => "Deca",
+            Prefix::Hecto => "Hecto",
+            Prefix::Kilo => "Kilo",
+            Prefix::Mega => "Mega",
+            Prefix::Giga => "Giga",
+            Prefix::Tera => "Tera",
+            Prefix::Peta => "Peta",
+            Prefix::Exa => "Exa",
+            Prefix::Zetta => "Zetta",
+            Prefix::Yotta => "Yotta",
+            Prefix::Ronna => "Ronna",
+            Prefix::Quetta => "Quetta",
+            Prefix::Kibi => "Kibi",
+            Prefix::Mebi => "Mebi",
+            Prefix::Gibi => "Gibi",
+            Prefix::Tebi => "Tebi",
+            Prefix::Pebi => "Pebi",
+            Prefix::Exbi => "Exbi",
+            Prefix::Zebi => "Zebi",
+            Prefix::Yobi => "Yobi",
+        }
+    }
+
+    /// The power of ten for decimal prefixes, [`None`] for binary ones.
+    pub const fn exponent(self) -> Option<i32> {
+        Some(match self {
+            Prefix::Quecto => -30,
+            Prefix::Ronto => -27,
+            Prefix::Yocto => -24,
+            Prefix::Zepto => -21,
+            Prefix::Atto => -18,
+            Prefix::Femto => -15,
+            Prefix::Pico => -12,
+            Prefix::Nano => -9,
+            Prefix::Micro => -6,
+            Prefix::Milli => -3,
+            Prefix::Centi => -2,
+            Prefix::Deci => -1,
+            Prefix::Deca => 1,
+            Prefix::Hecto => 2,
+            Prefix::Kilo => 3,
+            Prefix::Mega => 6,
+            Prefix::Giga => 9,
+            Prefix::Tera => 12,
+            Prefix::Peta => 15,
+            Prefix::Exa => 18,
+            Prefix::Zetta => 21,
+            Prefix::Yotta => 24,
+            Prefix::Ronna => 27,
+            Prefix::Quetta => 30,
+            _ => return None,
+        })
+    }
+
+    pub const fn factor(self) -> Factor {
+        match self.exponent() {
+            Some(exponent) if exponent < 0 => Factor::integer(10u128.pow(-exponent as u32)).recip(),
+            Some(exponent) => Factor::integer(10u128.pow(exponent as u32)),
+            None => {
+                let power = match self {
+                    Prefix::Kibi => 1,
+                    Prefix::Mebi => 2,
+                    Prefix::Gibi => 3,
+                    Prefix::Tebi => 4,
+                    Prefix::Pebi => 5,
+                    Prefix::Exbi => 6,
+                    Prefix::Zebi => 7,
+                    _ => 8,
+                };
+                Factor::integer(1 << (10 * power))
+            }
+        }
+    }
+
+    /// Parses a prefix symbol, `u` is accepted for `µ`.
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        match symbol {
+            "u" => Some(Prefix::Micro),
+            symbol => Self::all().find(|prefix| prefix.symbol() == symbol),
+        }
+    }
+
+    /// The decimal prefix with an exponent that's a multiple of 3 which puts `value` in
+    /// `1..1000`, [`None`] if it's already in that range or is zero.
+    pub fn best_for(value: f64) -> Option<Self> {
+        let magnitude = value.abs();
+        if magnitude == 0.0 || !magnitude.is_finite() || (1.0..1000.0).contains(&magnitude) {
+            return None;
+        }
+        Self::DECIMAL
+            .iter()
+            .rev()
+            .copied()
+            .filter(|prefix| prefix.exponent().is_some_and(|exponent| exponent % 3 == 0))
+            .find(|prefix| magnitude >= f64::from_factor(prefix.factor()))
+            .or(Some(Prefix::Quecto))
+    }
+}
+impl fmt::Display for Prefix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+/// A standard or registered custom unit with an optional prefix, e.g. `Gm` or `kA`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrefixedUnit {
+    pub prefix: Option<Prefix>,
+    pub unit: UnitTableEntry,
+}
+impl PrefixedUnit {
+    pub fn symbol(&self) -> String {
+        match self.prefix {
+            Some(prefix) => format!("{}{}", prefix.symbol(), self.unit.symbol),
+            None => self.unit.symbol.clone(),
+        }
+    }
+
+    pub fn name(&self) -> String {
+        match self.prefix {
+            Some(prefix) => format!("{}{}", prefix.name(), self.unit.name.to_lowercase()),
+            None => self.unit.name.clone(),
+        }
+    }
+
+    pub fn dims(&self) -> UnitDims {
+        self.unit.dims
+    }
+
+    /// [`None`] if the exact amount doesn't fit in a [`Factor`], see
+    /// [`exact_amount_of_base`](Self::exact_amount_of_base).
+    pub fn amount_of_base(&self) -> Option<Factor> {
+        match self.prefix {
+            Some(prefix) => self.unit.amount_of_base.checked_mul(prefix.factor()),
+            None => Some(self.unit.amount_of_base),
+        }
+    }
+
+    pub fn exact_amount_of_base(&self) -> ExactValue {
+        let amount = self.unit.amount_of_base.to_exact();
+        match self.prefix {
+            Some(prefix) => amount * prefix.factor().to_exact(),
+            None => amount,
+        }
+    }
+
+    pub fn amount_of_base_as<D>(&self) -> D
+    where
+        D: DisplayNumber,
+    {
+        let amount = D::from_factor(self.unit.amount_of_base);
+        match self.prefix {
+            Some(prefix) => amount * D::from_factor(prefix.factor()),
+            None => amount,
+        }
+    }
+}
+
+/// A value shown with the prefix that keeps it readable, e.g. `1.5e9 m` as `1.5 Gm`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrefixedValue {
+    pub value: f64,
+    pub unit: PrefixedUnit,
+}
+impl PrefixedValue {
+    /// `value` is in `unit`, which should be unprefixed.
+    pub fn new(value: f64, unit: UnitTableEntry) -> Self {
+        let prefix = Prefix::best_for(value);
+        let value = match prefix {
+            Some(prefix) => value / f64::from_factor(prefix.factor()),
+            None => value,
+        };
+        Self {
+            value,
+            unit: PrefixedUnit { prefix, unit },
+        }
+    }
+}
+impl fmt::Display for PrefixedValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)?;
+        write!(f, " {}", self.unit.symbol())
+    }
+}
+
+impl UnitRegistry {
+    /// Looks up `symbol` as a unit, then as a prefix followed by a unit. `u` is accepted for `µ`.
+    pub fn parse_prefixed(&self, symbol: &str) -> Option<PrefixedUnit> {
+        if let Some(unit) = self.entry(symbol) {
+            return Some(PrefixedUnit { prefix: None, unit });
+        }
+        // A symbol starting with `da` is only ever read as deca, `da` alone would otherwise be
+        // `d` followed by `a`, a deci-year.
+        let length = if symbol.get(..2).and_then(Prefix::from_symbol).is_some() { 2 } else { 1 };
+        let prefix = Prefix::from_symbol(symbol.get(..length)?)?;
+        let unit = self.entry(&symbol[length..])?;
+        Some(PrefixedUnit {
+            prefix: Some(prefix),
+            unit,
+        })
+    }
+
+    fn entry(&self, symbol: &str) -> Option<UnitTableEntry> {
+        self.entries().find(|entry| entry.symbol == symbol).cloned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::convert::TryFrom;
+
+    use crate::units::*;
+
+    #[test]
+    fn factor_test() {
+        assert_eq!(Factor::integer(1000), Prefix::Kilo.factor());
+        assert_eq!(Factor::integer(10u128.pow(30)), Prefix::Quetta.factor());
+        assert_eq!(
+            Factor::integer(10u128.pow(30)).recip(),
+            Prefix::Quecto.factor()
+        );
+        assert_eq!(Factor::integer(1024), Prefix::Kibi.factor());
+        assert_eq!(Factor::integer(1 << 80), Prefix::Yobi.factor());
+        assert_eq!(Some(Prefix::Micro), Prefix::from_symbol("u"));
+        assert_eq!(Some(Prefix::Deca), Prefix::from_symbol("da"));
+    }
+
+    #[test]
+    fn parse_test() {
+        let registry = UnitRegistry::new();
+        let gigameter = registry.parse_prefixed("Gm").unwrap();
+        assert_eq!(Some(Prefix::Giga), gigameter.prefix);
+        assert_eq!("Gigameter", gigameter.name());
+        assert_eq!(
+            Some(LengthUnit::MEGAMETER_FACTOR.mul(Factor::integer(1000))),
+            gigameter.amount_of_base()
+        );
+
+        let kiloampere = registry.parse_prefixed("kA").unwrap();
+        assert_eq!(UnitDims::ELECTRIC_CURRENT, kiloampere.dims());
+        assert_eq!(1e15, kiloampere.amount_of_base_as::<f64>());
+
+        // Existing symbols win, `min` is a minute.
+        assert_eq!(None, registry.parse_prefixed("min").unwrap().prefix);
+        assert_eq!("dam", registry.parse_prefixed("dam").unwrap().symbol());
+        assert_eq!("µs", registry.parse_prefixed("us").unwrap().symbol());
+        assert_eq!(None, registry.parse_prefixed("Xm"));
+        assert_eq!(None, registry.parse_prefixed("k"));
+        assert_eq!(None, registry.parse_prefixed("da"));
+        assert_eq!("da", registry.parse_prefixed("dau").unwrap().prefix.unwrap().symbol());
+
+        // Too big for a `Factor` but still exact as an `ExactValue`.
+        let huge = registry.parse_prefixed("QM☉").unwrap();
+        assert_eq!(None, huge.amount_of_base());
+        assert!(huge.amount_of_base_as::<f64>() > 1e65);
+        assert_eq!(
+            parse_exact("1.98847e66").unwrap(),
+            huge.exact_amount_of_base()
+        );
+    }
+
+    #[test]
+    fn display_test() {
+        let meter = UnitTableEntry::try_from(StandardUnit::Length(LengthUnit::Meter)).unwrap();
+        assert_eq!(
+            "1.5 Gm",
+            PrefixedValue::new(1.5e9, meter.clone()).to_string()
+        );
+        assert_eq!(
+            "250 m",
+            PrefixedValue::new(250.0, meter.clone()).to_string()
+        );
+        assert_eq!(
+            "-20 mm",
+            PrefixedValue::new(-0.02, meter.clone()).to_string()
+        );
+        assert_eq!("0 m", PrefixedValue::new(0.0, meter.clone()).to_string());
+        assert_eq!("1000 Qm", format!("{:.0}", PrefixedValue::new(1e33, meter)));
+    }
+}
@@ -0,0 +1,333 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::*;
+use core::str::FromStr;
+
+use num_traits::float::FloatCore;
+use num_traits::{NumCast, ToPrimitive};
+
+use crate::units::__private::powi;
+use crate::units::{
+    DimsType, DisplayNumber, KindTag, StandardUnit, UnitDims, UnitError, UnitRegistry, UnitValue,
+    Value,
+};
+
+/// A value in base units whose dimensions are only known at runtime, for scripting and
+/// bindings where [`UnitValue`] can't be used.
+///
+/// Arithmetic that needs equal dimensions returns [`None`] on a mismatch.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Quantity {
+    value: f64,
+    dims: UnitDims,
+}
+impl Quantity {
+    pub const fn new(value: f64, dims: UnitDims) -> Self {
+        Self { value, dims }
+    }
+
+    pub const fn dimensionless(value: f64) -> Self {
+        Self::new(value, UnitDims::DIMENSIONLESS)
+    }
+
+    pub fn from_unit(value: f64, unit: StandardUnit) -> Self {
+        let factor: f64 = DisplayNumber::from_factor(unit.amount_of_base());
+        Self::new(value * factor, unit.dims())
+    }
+
+    pub const fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub const fn dims(&self) -> UnitDims {
+        self.dims
+    }
+
+    /// The value in `unit`, [`None`] if the dimensions differ.
+    pub fn in_unit(&self, unit: StandardUnit) -> Option<f64> {
+        if self.dims != unit.dims() {
+            return None;
+        }
+        let factor: f64 = DisplayNumber::from_factor(unit.amount_of_base());
+        Some(self.value / factor)
+    }
+
+    /// [`None`] if the dimensions differ or the value doesn't fit in `T`, which for integers
+    /// includes values with a fractional part.
+    pub fn to_unit_value<T, const DIMS: UnitDims>(self) -> Option<UnitValue<T, DIMS>>
+    where
+        T: Value + NumCast,
+    {
+        if self.dims != DIMS || loses_fraction::<T>(self.value) {
+            return None;
+        }
+        T::from(self.value).map(UnitValue::new)
+    }
+
+    pub fn try_in_unit(&self, unit: StandardUnit) -> Result<f64, UnitError> {
+        unit.dims().try_eq(self.dims)?;
+        self.in_unit(unit).ok_or(UnitError::Overflow)
+    }
+
+    /// Like [`Quantity::to_unit_value`], with [`UnitError::PrecisionLoss`] when an integer `T`
+    /// would drop a fractional part.
+    pub fn try_to_unit_value<T, const DIMS: UnitDims>(self) -> Result<UnitValue<T, DIMS>, UnitError>
+    where
+        T: Value + NumCast,
+    {
+        DIMS.try_eq(self.dims)?;
+        if loses_fraction::<T>(self.value) {
+            return Err(UnitError::PrecisionLoss);
+        }
+        self.to_unit_value().ok_or(UnitError::Overflow)
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        if self.dims == rhs.dims {
+            Some(Self::new(self.value + rhs.value, self.dims))
+        } else {
+            None
+        }
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(-rhs)
+    }
+
+    pub fn try_add(self, rhs: Self) -> Result<Self, UnitError> {
+        self.dims.try_eq(rhs.dims)?;
+        Ok(Self::new(self.value + rhs.value, self.dims))
+    }
+
+    pub fn try_sub(self, rhs: Self) -> Result<Self, UnitError> {
+        self.try_add(-rhs)
+    }
+
+    pub fn powi(self, exponent: i32) -> Self {
+        Self::new(
+            powi(self.value, exponent),
+            self.dims.mul(exponent as DimsType),
+        )
+    }
+}
+impl<T, const DIMS: UnitDims, K> From<UnitValue<T, DIMS, K>> for Quantity
+where
+    T: Value + ToPrimitive,
+    K: KindTag,
+{
+    fn from(from: UnitValue<T, DIMS, K>) -> Self {
+        Self::new(from.value().to_f64().unwrap(), DIMS)
+    }
+}
+/// Whether `T` is an integer type, where `NumCast` would truncate a fraction.
+fn is_integer<T>() -> bool
+where
+    T: NumCast,
+{
+    T::from(0.5).and_then(|half| half.to_f64()) != Some(0.5)
+}
+/// Whether casting `value` to `T` would drop a fractional part. `f64::fract` needs std.
+fn loses_fraction<T>(value: f64) -> bool
+where
+    T: NumCast,
+{
+    is_integer::<T>() && FloatCore::fract(value) != 0.0
+}
+
+impl Quantity {
+    /// Parses a number followed by a unit symbol known to `registry`, which may carry a prefix,
+    /// e.g. `3.5 km` or `2 Gm`.
+    pub fn parse_with(s: &str, registry: &UnitRegistry) -> Result<Self, UnitError> {
+        let number_start = s.len() - s.trim_start().len();
+        let number_end = s[number_start..]
+            .find(char::is_whitespace)
+            .map_or(s.len(), |index| number_start + index);
+        let value = s[number_start..number_end]
+            .parse::<f64>()
+            .map_err(|_| UnitError::Parse {
+                message: "invalid number",
+                span: number_start..number_end,
+            })?;
+        let symbol = s[number_end..].trim();
+        if symbol.is_empty() {
+            return Ok(Self::dimensionless(value));
+        }
+        let unit = registry
+            .parse_prefixed(symbol)
+            .ok_or_else(|| UnitError::UnknownUnit(symbol.into()))?;
+        Ok(Self::new(value * unit.amount_of_base_as::<f64>(), unit.dims()))
+    }
+}
+/// Like [`Quantity::parse_with`] with only the standard units.
+impl FromStr for Quantity {
+    type Err = UnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, &UnitRegistry::new())
+    }
+}
+impl PartialOrd for Quantity {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.dims == other.dims {
+            self.value.partial_cmp(&other.value)
+        } else {
+            None
+        }
+    }
+}
+impl fmt::Display for Quantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.dims == UnitDims::DIMENSIONLESS {
+            write!(f, "{}", self.value)
+        } else {
+            write!(f, "{} {}", self.value, self.dims)
+        }
+    }
+}
+impl Neg for Quantity {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.value, self.dims)
+    }
+}
+impl Mul for Quantity {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(self.value * rhs.value, self.dims.add(rhs.dims))
+    }
+}
+impl Mul<f64> for Quantity {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::new(self.value * rhs, self.dims)
+    }
+}
+impl Div for Quantity {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::new(self.value / rhs.value, self.dims.sub(rhs.dims))
+    }
+}
+impl Div<f64> for Quantity {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        Self::new(self.value / rhs, self.dims)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::qty;
+    use crate::units::*;
+
+    #[test]
+    fn quantity_test() {
+        let distance = Quantity::from_unit(3.0, StandardUnit::Length(LengthUnit::Kilometer));
+        let time = Quantity::from_unit(2.0, StandardUnit::Time(TimeUnit::Minute));
+        let speed = distance / time;
+        assert_eq!(Quantity::from(qty!(25 m / s)), speed);
+        assert_eq!(None, distance.checked_add(time));
+        assert_eq!(None, distance.partial_cmp(&time));
+        assert_eq!(
+            Some(3500.0),
+            distance
+                .checked_add(Quantity::from_unit(
+                    500.0,
+                    StandardUnit::Length(LengthUnit::Meter)
+                ))
+                .and_then(|sum| sum.in_unit(StandardUnit::Length(LengthUnit::Meter)))
+        );
+        assert_eq!(None, speed.in_unit(StandardUnit::Length(LengthUnit::Meter)));
+        assert!(speed
+            .to_unit_value::<f64, { UnitDims::LENGTH.sub(UnitDims::TIME) }>()
+            .is_some());
+        assert_eq!(
+            Some(3_000_000_000_000),
+            distance
+                .to_unit_value::<i64, { UnitDims::LENGTH }>()
+                .map(UnitValue::into_inner)
+        );
+        assert_eq!(None, distance.to_unit_value::<u8, { UnitDims::LENGTH }>());
+        let fraction = Quantity::new(2.7, UnitDims::LENGTH);
+        assert_eq!(None, fraction.to_unit_value::<i64, { UnitDims::LENGTH }>());
+        assert!(fraction
+            .to_unit_value::<f32, { UnitDims::LENGTH }>()
+            .is_some());
+        assert_eq!(UnitDims::LENGTH.mul(2), distance.powi(2).dims());
+    }
+
+    #[test]
+    fn try_test() {
+        let distance = Quantity::from(qty!(3 km));
+        let time = Quantity::from(qty!(2 min));
+        assert_eq!(
+            Err(UnitError::DimensionMismatch {
+                expected: UnitDims::LENGTH,
+                found: UnitDims::TIME,
+            }),
+            distance.try_add(time)
+        );
+        assert_eq!(
+            Ok(Quantity::from(qty!(2.5 km))),
+            distance.try_sub(Quantity::from(qty!(500 m)))
+        );
+        assert!(matches!(
+            distance.try_in_unit(StandardUnit::Time(TimeUnit::Second)),
+            Err(UnitError::DimensionMismatch { .. })
+        ));
+        assert_eq!(
+            Err(UnitError::Overflow),
+            distance.try_to_unit_value::<u8, { UnitDims::LENGTH }>()
+        );
+        assert_eq!(
+            Err(UnitError::PrecisionLoss),
+            Quantity::new(2.7, UnitDims::LENGTH).try_to_unit_value::<i64, { UnitDims::LENGTH }>()
+        );
+    }
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(Ok(Quantity::from(qty!(3.5 km))), " 3.5 km".parse());
+        assert_eq!(Ok(Quantity::from(qty!(2e9 m))), "2 Gm".parse());
+        assert_eq!(Ok(Quantity::dimensionless(4.0)), "4".parse());
+        assert_eq!(
+            Err(UnitError::Parse {
+                message: "invalid number",
+                span: 1..4,
+            }),
+            " 3x5 km".parse::<Quantity>()
+        );
+        assert_eq!(
+            Err(UnitError::UnknownUnit("furlong".to_string())),
+            "1 furlong".parse::<Quantity>()
+        );
+
+        let mut registry = UnitRegistry::new();
+        registry
+            .register(
+                "Furlong",
+                "furlong",
+                UnitDims::LENGTH,
+                Factor::from_decimal("201.168").mul(LengthUnit::METER_FACTOR),
+            )
+            .unwrap();
+        assert_eq!(
+            Ok(Quantity::from(qty!(2011.68 m))),
+            Quantity::parse_with("10 furlong", &registry)
+        );
+    }
+
+    #[test]
+    fn display_test() {
+        assert_eq!(
+            "1.5 nm ns^-1",
+            Quantity::new(1.5, UnitDims::LENGTH.sub(UnitDims::TIME)).to_string()
+        );
+        assert_eq!("2", Quantity::dimensionless(2.0).to_string());
+    }
+}
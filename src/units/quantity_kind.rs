@@ -0,0 +1,219 @@
+use core::fmt;
+
+use crate::units::{kinds, KindTag, UnitDims, UnitValue};
+
+macro_rules! quantity_kinds {
+    (
+        base {
+            $($base:ident($base_name:literal) = $base_const:ident;)+
+        }
+        derived {
+            $($kind:ident($name:literal) = $konst:ident { $($dim:ident: $power:literal),+ };)+
+        }
+    ) => {
+        impl UnitDims {
+            $(
+                pub const $konst: Self = Self {
+                    $($dim: $power,)+
+                    ..Self::DIMENSIONLESS
+                };
+            )+
+        }
+
+        $(pub type $base<T> = UnitValue<T, { UnitDims::$base_const }>;)+
+        $(pub type $kind<T> = UnitValue<T, { UnitDims::$konst }>;)+
+
+        /// A well known quantity, each has a [`UnitDims`] const and a [`UnitValue`] alias of the
+        /// same name, e.g. [`UnitDims::VELOCITY`] and [`Velocity<T>`](Velocity).
+        #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+        pub enum QuantityKind {
+            $($base,)+
+            $($kind,)+
+        }
+        impl QuantityKind {
+            pub const ALL: &'static [Self] = &[$(Self::$base,)+ $(Self::$kind,)+];
+
+            pub const fn dims(self) -> UnitDims {
+                match self {
+                    $(Self::$base => UnitDims::$base_const,)+
+                    $(Self::$kind => UnitDims::$konst,)+
+                }
+            }
+
+            /// The lower case name, e.g. `"velocity"`.
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(Self::$base => $base_name,)+
+                    $(Self::$kind => $name,)+
+                }
+            }
+
+            const fn const_name(self) -> &'static str {
+                match self {
+                    $(Self::$base => stringify!($base_const),)+
+                    $(Self::$kind => stringify!($konst),)+
+                }
+            }
+        }
+    };
+}
+
+quantity_kinds! {
+    base {
+        Dimensionless("dimensionless") = DIMENSIONLESS;
+        Time("time") = TIME;
+        Length("length") = LENGTH;
+        Mass("mass") = MASS;
+        ElectricCurrent("electric current") = ELECTRIC_CURRENT;
+        Temperature("temperature") = TEMPERATURE;
+        Amount("amount") = AMOUNT;
+        LuminousIntensity("luminous intensity") = LUMINOUS_INTENSITY;
+    }
+    derived {
+        Area("area") = AREA { length: 2 };
+        Volume("volume") = VOLUME { length: 3 };
+        Frequency("frequency") = FREQUENCY { time: -1 };
+        Velocity("velocity") = VELOCITY { length: 1, time: -1 };
+        Acceleration("acceleration") = ACCELERATION { length: 1, time: -2 };
+        Jerk("jerk") = JERK { length: 1, time: -3 };
+        Density("density") = DENSITY { mass: 1, length: -3 };
+        MassFlowRate("mass flow rate") = MASS_FLOW_RATE { mass: 1, time: -1 };
+        Momentum("momentum") = MOMENTUM { mass: 1, length: 1, time: -1 };
+        Force("force") = FORCE { mass: 1, length: 1, time: -2 };
+        Pressure("pressure") = PRESSURE { mass: 1, length: -1, time: -2 };
+        Energy("energy") = ENERGY { mass: 1, length: 2, time: -2 };
+        Power("power") = POWER { mass: 1, length: 2, time: -3 };
+        Action("action") = ACTION { mass: 1, length: 2, time: -1 };
+        Irradiance("irradiance") = IRRADIANCE { mass: 1, time: -3 };
+        Charge("charge") = CHARGE { electric_current: 1, time: 1 };
+        Voltage("voltage") = VOLTAGE { mass: 1, length: 2, time: -3, electric_current: -1 };
+        Resistance("resistance") = RESISTANCE {
+            mass: 1,
+            length: 2,
+            time: -3,
+            electric_current: -2
+        };
+        Capacitance("capacitance") = CAPACITANCE {
+            mass: -1,
+            length: -2,
+            time: 4,
+            electric_current: 2
+        };
+        Permittivity("permittivity") = PERMITTIVITY {
+            mass: -1,
+            length: -3,
+            time: 4,
+            electric_current: 2
+        };
+        Permeability("permeability") = PERMEABILITY {
+            mass: 1,
+            length: 1,
+            time: -2,
+            electric_current: -2
+        };
+        Entropy("entropy") = ENTROPY { mass: 1, length: 2, time: -2, temperature: -1 };
+        MolarEntropy("molar entropy") = MOLAR_ENTROPY {
+            mass: 1,
+            length: 2,
+            time: -2,
+            temperature: -1,
+            amount: -1
+        };
+        InverseAmount("inverse amount") = INVERSE_AMOUNT { amount: -1 };
+    }
+}
+
+/// Shares its dims with [`Energy`], convert with [`UnitValue::with_kind`].
+pub type Torque<T> = UnitValue<T, { <kinds::Torque as KindTag>::DIMS }, kinds::Torque>;
+/// Shares its dims with [`Frequency`].
+pub type Radioactivity<T> =
+    UnitValue<T, { <kinds::Radioactivity as KindTag>::DIMS }, kinds::Radioactivity>;
+/// Shares its dims with [`Frequency`].
+pub type AngularVelocity<T> =
+    UnitValue<T, { <kinds::AngularVelocity as KindTag>::DIMS }, kinds::AngularVelocity>;
+
+impl QuantityKind {
+    pub fn from_dims(dims: UnitDims) -> Option<Self> {
+        Self::ALL.iter().copied().find(|kind| kind.dims() == dims)
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|kind| kind.name() == name)
+    }
+}
+impl fmt::Display for QuantityKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl UnitDims {
+    pub fn kind(self) -> Option<QuantityKind> {
+        QuantityKind::from_dims(self)
+    }
+}
+/// Known dims are written as their const, e.g. `UnitDims::VELOCITY`.
+impl fmt::Debug for UnitDims {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(kind) = self.kind() {
+            return write!(f, "UnitDims::{}", kind.const_name());
+        }
+        f.debug_struct("UnitDims")
+            .field("time", &self.time)
+            .field("length", &self.length)
+            .field("mass", &self.mass)
+            .field("electric_current", &self.electric_current)
+            .field("temperature", &self.temperature)
+            .field("amount", &self.amount)
+            .field("luminous_intensity", &self.luminous_intensity)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::format;
+
+    use crate::qty;
+    use crate::units::*;
+
+    #[test]
+    fn kind_test() {
+        assert_eq!(Some(QuantityKind::Force), UnitDims::FORCE.kind());
+        assert_eq!(
+            Some(QuantityKind::Velocity),
+            UnitDims::LENGTH.sub(UnitDims::TIME).kind()
+        );
+        assert_eq!(None, UnitDims::LENGTH.mul(5).kind());
+        assert_eq!(
+            Some(QuantityKind::Pressure),
+            QuantityKind::from_name("pressure")
+        );
+        for kind in QuantityKind::ALL {
+            assert_eq!(
+                Some(*kind),
+                QuantityKind::from_dims(kind.dims()),
+                "{}",
+                kind
+            );
+            assert_eq!(Some(*kind), QuantityKind::from_name(kind.name()));
+        }
+
+        let speed: Velocity<f64> = qty!(3 km / s);
+        let thrust: Force<f64> = qty!(2 kg m s^-2);
+        assert_eq!(3e12 / 1e9, *speed.value());
+        assert_eq!(UnitDims::FORCE, QuantityKind::Force.dims());
+        let _: Momentum<f64> = qty!(1 kg) * speed;
+        let _: Power<f64> = thrust * speed;
+    }
+
+    #[test]
+    fn debug_test() {
+        assert_eq!("UnitDims::ENERGY", format!("{:?}", UnitDims::ENERGY));
+        assert_eq!(
+            "UnitDims { time: 0, length: 5, mass: 0, electric_current: 0, temperature: 0, \
+             amount: 0, luminous_intensity: 0 }",
+            format!("{:?}", UnitDims::LENGTH.mul(5))
+        );
+    }
+}
@@ -0,0 +1,231 @@
+//! Distributions over [`UnitValue`]s, enabled by the `rand` feature.
+//!
+//! Sampling is deterministic for a given rng, so a seeded rng such as
+//! `StdRng::seed_from_u64` reproduces the same values.
+
+use core::f64::consts::TAU;
+use core::marker::PhantomData;
+
+use rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler};
+use rand::distributions::Distribution;
+use rand::Rng;
+
+use crate::units::{Generic, KindTag, UnitDims, UnitValue, Value};
+
+/// Lets [`Uniform`](rand::distributions::Uniform) and [`Rng::gen_range`] sample over a range of
+/// [`UnitValue`]s.
+impl<T, const DIMS: UnitDims, K> SampleUniform for UnitValue<T, DIMS, K>
+where
+    T: Value + SampleUniform,
+    K: KindTag,
+{
+    type Sampler = UniformUnitValue<T, DIMS, K>;
+}
+
+#[derive(Clone, Debug)]
+pub struct UniformUnitValue<T, const DIMS: UnitDims, K>(T::Sampler, PhantomData<K>)
+where
+    T: SampleUniform;
+impl<T, const DIMS: UnitDims, K> UniformSampler for UniformUnitValue<T, DIMS, K>
+where
+    T: Value + SampleUniform,
+    K: KindTag,
+{
+    type X = UnitValue<T, DIMS, K>;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self(
+            T::Sampler::new(low.borrow().value(), high.borrow().value()),
+            PhantomData,
+        )
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self(
+            T::Sampler::new_inclusive(low.borrow().value(), high.borrow().value()),
+            PhantomData,
+        )
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        UnitValue::new(self.0.sample(rng))
+    }
+}
+
+/// Uniform in the logarithm of the value, for ranges spanning many orders of magnitude such as
+/// stellar masses.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LogUniform<const DIMS: UnitDims, K = Generic>
+where
+    K: KindTag,
+{
+    ln_low: f64,
+    ln_high: f64,
+    kind: PhantomData<K>,
+}
+impl<const DIMS: UnitDims, K> LogUniform<DIMS, K>
+where
+    K: KindTag,
+{
+    /// Samples from `[low, high)`, panics unless `0 < low <= high`.
+    pub fn new(low: UnitValue<f64, DIMS, K>, high: UnitValue<f64, DIMS, K>) -> Self {
+        let (low, high) = (low.into_inner(), high.into_inner());
+        assert!(
+            low > 0.0 && low <= high && high.is_finite(),
+            "Log uniform range must be positive and finite, got {}..{}",
+            low,
+            high
+        );
+        Self {
+            ln_low: low.ln(),
+            ln_high: high.ln(),
+            kind: PhantomData,
+        }
+    }
+}
+impl<const DIMS: UnitDims, K> Distribution<UnitValue<f64, DIMS, K>> for LogUniform<DIMS, K>
+where
+    K: KindTag,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> UnitValue<f64, DIMS, K> {
+        let exponent = self.ln_low + (self.ln_high - self.ln_low) * rng.gen::<f64>();
+        UnitValue::new(exponent.exp())
+    }
+}
+
+/// A normal distribution with a dimensioned mean and standard deviation.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Normal<const DIMS: UnitDims, K = Generic>
+where
+    K: KindTag,
+{
+    mean: UnitValue<f64, DIMS, K>,
+    std_dev: UnitValue<f64, DIMS, K>,
+}
+impl<const DIMS: UnitDims, K> Normal<DIMS, K>
+where
+    K: KindTag,
+{
+    /// Panics if `std_dev` is negative or not finite.
+    pub fn new(mean: UnitValue<f64, DIMS, K>, std_dev: UnitValue<f64, DIMS, K>) -> Self {
+        assert!(
+            std_dev.value().is_finite() && *std_dev.value() >= 0.0,
+            "Standard deviation must be finite and not negative, got {}",
+            std_dev.value()
+        );
+        Self { mean, std_dev }
+    }
+
+    pub const fn mean(&self) -> UnitValue<f64, DIMS, K> {
+        self.mean
+    }
+
+    pub const fn std_dev(&self) -> UnitValue<f64, DIMS, K> {
+        self.std_dev
+    }
+}
+impl<const DIMS: UnitDims, K> Distribution<UnitValue<f64, DIMS, K>> for Normal<DIMS, K>
+where
+    K: KindTag,
+{
+    /// Box-Muller transform.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> UnitValue<f64, DIMS, K> {
+        // `1 - x` keeps the logarithm away from zero.
+        let radius = (-2.0 * (1.0 - rng.gen::<f64>()).ln()).sqrt();
+        let standard = radius * (TAU * rng.gen::<f64>()).cos();
+        UnitValue::new(self.mean.into_inner() + standard * self.std_dev.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec::Vec;
+
+    use rand::distributions::{Distribution, Uniform};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use crate::qty;
+    use crate::units::*;
+
+    #[test]
+    fn uniform_test() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let low: Length<f64> = qty!(1 km);
+        let high: Length<f64> = qty!(2 km);
+        let radius = Uniform::new(low, high);
+        for _ in 0..100 {
+            let sample = radius.sample(&mut rng);
+            assert!(sample >= low && sample < high, "{:?}", sample);
+        }
+
+        let period = rng.gen_range(GSDuration::new(10)..=GSDuration::new(20));
+        assert!((10..=20).contains(period.value()));
+    }
+
+    #[test]
+    fn log_uniform_test() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let low: Mass<f64> = qty!(1 kg);
+        let high: Mass<f64> = qty!(1 SolarMass);
+        let masses = LogUniform::new(low, high);
+        let samples: Vec<_> = masses.sample_iter(&mut rng).take(1000).collect();
+        assert!(samples.iter().all(|mass| *mass >= low && *mass < high));
+        // About half the samples are below the geometric mean.
+        let middle = (low.into_inner() * high.into_inner()).sqrt();
+        let below = samples.iter().filter(|mass| *mass.value() < middle).count();
+        assert!((400..600).contains(&below), "{}", below);
+    }
+
+    #[test]
+    fn normal_test() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let temperature = Normal::new(
+            UnitValue::<f64, { UnitDims::TEMPERATURE }>::new(5772.0),
+            UnitValue::new(100.0),
+        );
+        let samples: Vec<_> = temperature.sample_iter(&mut rng).take(10_000).collect();
+        let mean = samples.iter().map(|t| t.value()).sum::<f64>() / samples.len() as f64;
+        let variance = samples
+            .iter()
+            .map(|t| (t.value() - mean).powi(2))
+            .sum::<f64>()
+            / samples.len() as f64;
+        assert!((mean - 5772.0).abs() < 5.0, "{}", mean);
+        assert!((variance.sqrt() - 100.0).abs() < 5.0, "{}", variance.sqrt());
+    }
+
+    #[test]
+    fn seeded_test() {
+        let distance = Normal::new(
+            UnitValue::<f64, { UnitDims::LENGTH }>::new(1.0),
+            UnitValue::new(0.5),
+        );
+        let first: Vec<_> = distance
+            .sample_iter(StdRng::seed_from_u64(42))
+            .take(10)
+            .collect();
+        let second: Vec<_> = distance
+            .sample_iter(StdRng::seed_from_u64(42))
+            .take(10)
+            .collect();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    #[should_panic]
+    fn log_uniform_zero_test() {
+        LogUniform::new(
+            UnitValue::<f64, { UnitDims::MASS }>::new(0.0),
+            UnitValue::new(1.0),
+        );
+    }
+}
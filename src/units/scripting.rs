@@ -0,0 +1,223 @@
+use core::convert::TryFrom;
+
+use num_traits::NumCast;
+use rhai::{Dynamic, Engine, EvalAltResult, FLOAT, INT};
+
+use crate::units::{Quantity, StandardUnit, UnitDims, UnitError, UnitValue, Value};
+
+type ScriptResult<T> = Result<T, Box<EvalAltResult>>;
+
+/// The name of the constructor function for `unit`, its symbol with non ASCII symbols spelled
+/// out the same way as in [`qty!`](crate::qty).
+fn script_name(unit: StandardUnit) -> String {
+    let symbol = unit.symbol().replace('µ', "u");
+    if symbol.is_ascii() {
+        symbol
+    } else {
+        unit.name().to_string()
+    }
+}
+
+fn find_unit(symbol: &str) -> ScriptResult<StandardUnit> {
+    StandardUnit::all()
+        .find(|unit| unit.symbol() == symbol || script_name(*unit) == symbol)
+        .ok_or_else(|| format!("unknown unit symbol `{}`", symbol).into())
+}
+
+fn mismatch(expected: UnitDims, found: UnitDims) -> Box<EvalAltResult> {
+    UnitError::DimensionMismatch { expected, found }
+        .to_string()
+        .into()
+}
+
+fn checked(
+    lhs: Quantity,
+    rhs: Quantity,
+    op: fn(Quantity, Quantity) -> Option<Quantity>,
+) -> ScriptResult<Quantity> {
+    op(lhs, rhs).ok_or_else(|| mismatch(lhs.dims(), rhs.dims()))
+}
+
+fn compare(lhs: Quantity, rhs: Quantity, op: fn(&f64, &f64) -> bool) -> ScriptResult<bool> {
+    if lhs.dims() == rhs.dims() {
+        Ok(op(&lhs.value(), &rhs.value()))
+    } else {
+        Err(mismatch(lhs.dims(), rhs.dims()))
+    }
+}
+
+/// Registers the `Quantity` type with `engine`.
+///
+/// Scripts build quantities with one constructor per standard unit, e.g. `km(3)` or
+/// `EarthMass(0.5)`, or with `quantity(3, "km")`, and read them back with `q.to("m")`.
+/// Operators that need equal dimensions raise a script error on a mismatch.
+pub fn register_units(engine: &mut Engine) {
+    engine
+        .register_type_with_name::<Quantity>("Quantity")
+        .register_fn("quantity", |value: FLOAT, symbol: &str| {
+            find_unit(symbol).map(|unit| Quantity::from_unit(value, unit))
+        })
+        .register_fn("quantity", |value: INT, symbol: &str| {
+            find_unit(symbol).map(|unit| Quantity::from_unit(value as f64, unit))
+        })
+        .register_get("value", |quantity: &mut Quantity| quantity.value())
+        .register_get("dims", |quantity: &mut Quantity| {
+            quantity.dims().to_string()
+        })
+        .register_fn("to", |quantity: &mut Quantity, symbol: &str| {
+            let unit = find_unit(symbol)?;
+            quantity
+                .in_unit(unit)
+                .ok_or_else(|| mismatch(unit.dims(), quantity.dims()))
+        })
+        .register_fn("to_string", |quantity: &mut Quantity| quantity.to_string())
+        .register_fn("to_debug", |quantity: &mut Quantity| {
+            format!("{:?}", quantity)
+        })
+        .register_fn("+", |lhs: Quantity, rhs: Quantity| {
+            checked(lhs, rhs, Quantity::checked_add)
+        })
+        .register_fn("-", |lhs: Quantity, rhs: Quantity| {
+            checked(lhs, rhs, Quantity::checked_sub)
+        })
+        .register_fn("-", |quantity: Quantity| -quantity)
+        .register_fn("*", |lhs: Quantity, rhs: Quantity| lhs * rhs)
+        .register_fn("*", |lhs: Quantity, rhs: FLOAT| lhs * rhs)
+        .register_fn("*", |lhs: FLOAT, rhs: Quantity| rhs * lhs)
+        .register_fn("*", |lhs: Quantity, rhs: INT| lhs * rhs as f64)
+        .register_fn("*", |lhs: INT, rhs: Quantity| rhs * lhs as f64)
+        .register_fn("/", |lhs: Quantity, rhs: Quantity| lhs / rhs)
+        .register_fn("/", |lhs: Quantity, rhs: FLOAT| lhs / rhs)
+        .register_fn("/", |lhs: FLOAT, rhs: Quantity| {
+            Quantity::dimensionless(lhs) / rhs
+        })
+        .register_fn("/", |lhs: Quantity, rhs: INT| lhs / rhs as f64)
+        .register_fn("/", |lhs: INT, rhs: Quantity| {
+            Quantity::dimensionless(lhs as f64) / rhs
+        })
+        .register_fn("**", |lhs: Quantity, rhs: INT| -> ScriptResult<Quantity> {
+            let exponent = i32::try_from(rhs)
+                .map_err(|_| format!("exponent {} is out of range", rhs))?;
+            Ok(lhs.powi(exponent))
+        })
+        .register_fn("==", |lhs: Quantity, rhs: Quantity| lhs == rhs)
+        .register_fn("!=", |lhs: Quantity, rhs: Quantity| lhs != rhs)
+        .register_fn("<", |lhs: Quantity, rhs: Quantity| {
+            compare(lhs, rhs, f64::lt)
+        })
+        .register_fn("<=", |lhs: Quantity, rhs: Quantity| {
+            compare(lhs, rhs, f64::le)
+        })
+        .register_fn(">", |lhs: Quantity, rhs: Quantity| {
+            compare(lhs, rhs, f64::gt)
+        })
+        .register_fn(">=", |lhs: Quantity, rhs: Quantity| {
+            compare(lhs, rhs, f64::ge)
+        });
+
+    for unit in StandardUnit::all() {
+        let name = script_name(unit);
+        engine
+            .register_fn(&name, move |value: FLOAT| Quantity::from_unit(value, unit))
+            .register_fn(&name, move |value: INT| {
+                Quantity::from_unit(value as f64, unit)
+            });
+    }
+}
+
+/// Converts a script result into a [`UnitValue`] at the host boundary, values are passed into
+/// scripts as [`Quantity::from`].
+pub fn unit_value_from_dynamic<T, const DIMS: UnitDims>(
+    value: Dynamic,
+) -> ScriptResult<UnitValue<T, DIMS>>
+where
+    T: Value + NumCast,
+{
+    let type_name = value.type_name();
+    let quantity = value
+        .try_cast::<Quantity>()
+        .ok_or_else(|| format!("expected a Quantity, got {}", type_name))?;
+    if quantity.dims() != DIMS {
+        return Err(mismatch(DIMS, quantity.dims()));
+    }
+    quantity
+        .to_unit_value()
+        .ok_or_else(|| format!("{} is out of range", quantity).into())
+}
+
+#[cfg(test)]
+mod test {
+    use rhai::{Dynamic, Engine, Scope};
+
+    use crate::qty;
+    use crate::units::*;
+
+    fn engine() -> Engine {
+        let mut engine = Engine::new();
+        register_units(&mut engine);
+        engine
+    }
+
+    #[test]
+    fn script_test() {
+        let engine = engine();
+        assert_eq!(
+            3500.0,
+            engine.eval::<f64>(r#"(km(3) + m(500)).to("m")"#).unwrap()
+        );
+        assert_eq!(
+            25.0,
+            engine
+                .eval::<f64>(r#"let v = quantity(3.0, "km") / min(2); (v * s(1)).to("m")"#)
+                .unwrap()
+        );
+        assert_eq!(
+            0.5,
+            engine
+                .eval::<f64>(r#"(EarthMass(1) / 2).to("M⊕")"#)
+                .unwrap()
+        );
+        assert!(engine.eval::<bool>(r#"kg(1) > g(999)"#).unwrap());
+        assert_eq!(
+            "nm^2",
+            engine.eval::<String>(r#"(nm(2) ** 2).dims"#).unwrap()
+        );
+
+        for script in &[r#"km(1) + s(1)"#, r#"kg(1) < m(1)"#, r#"km(1).to("s")"#] {
+            let error = engine.eval::<Dynamic>(script).unwrap_err();
+            assert!(
+                error.to_string().contains("dimension mismatch"),
+                "{}",
+                error
+            );
+        }
+        assert!(engine.eval::<Dynamic>(r#"quantity(1, "parsec")"#).is_err());
+        let error = engine.eval::<Dynamic>("m(1) ** 4294967298").unwrap_err();
+        assert!(error.to_string().contains("out of range"), "{}", error);
+    }
+
+    #[test]
+    fn host_boundary_test() {
+        let engine = engine();
+        let mut scope = Scope::new();
+        scope.push("thrust_time", Quantity::from(qty!(90 s)));
+        let distance = engine
+            .eval_with_scope::<Dynamic>(&mut scope, "km(2) / s(1) * thrust_time")
+            .unwrap();
+        let distance = unit_value_from_dynamic::<i64, { UnitDims::LENGTH }>(distance).unwrap();
+        assert_eq!(180_000_000_000_000, distance.into_inner());
+
+        // Integers can't hold a fraction of a nanosecond.
+        let time = engine.eval::<Dynamic>("ns(2.7)").unwrap();
+        assert!(unit_value_from_dynamic::<i64, { UnitDims::TIME }>(time.clone()).is_err());
+        assert_eq!(
+            2.7,
+            unit_value_from_dynamic::<f64, { UnitDims::TIME }>(time)
+                .unwrap()
+                .into_inner()
+        );
+
+        let time = engine.eval::<Dynamic>("h(1)").unwrap();
+        assert!(unit_value_from_dynamic::<f64, { UnitDims::LENGTH }>(time).is_err());
+    }
+}
@@ -0,0 +1,357 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+use crate::units::{DisplayNumber, DisplayValue, Factor, StandardUnit, Unit, UnitDims, UnitError};
+
+/// One row of a conversion table.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnitTableEntry {
+    pub family: String,
+    pub name: String,
+    pub symbol: String,
+    pub dims: UnitDims,
+    pub amount_of_base: Factor,
+}
+/// [`UnitError::Overflow`] if the amount of base doesn't fit in a [`Factor`].
+impl TryFrom<StandardUnit> for UnitTableEntry {
+    type Error = UnitError;
+
+    fn try_from(from: StandardUnit) -> Result<Self, Self::Error> {
+        Ok(Self {
+            family: from.family().to_string(),
+            name: from.name().to_string(),
+            symbol: from.symbol().to_string(),
+            dims: from.dims(),
+            amount_of_base: from.try_amount_of_base()?,
+        })
+    }
+}
+
+/// The standard units plus any custom units registered by the game, for lookups and exporting
+/// conversion tables.
+#[derive(Clone, Debug)]
+pub struct UnitRegistry {
+    // Built once, lookups by symbol go through these.
+    standard: Vec<UnitTableEntry>,
+    custom: Vec<UnitTableEntry>,
+}
+impl UnitRegistry {
+    pub fn new() -> Self {
+        Self {
+            standard: StandardUnit::all()
+                .map(|unit| {
+                    UnitTableEntry::try_from(unit).expect("Standard units fit in a Factor!")
+                })
+                .collect(),
+            custom: Vec::new(),
+        }
+    }
+
+    /// Registers a custom unit in the `custom` family, symbols must be unique across standard
+    /// and custom units.
+    pub fn register(
+        &mut self,
+        name: &str,
+        symbol: &str,
+        dims: UnitDims,
+        amount_of_base: Factor,
+    ) -> Result<(), RegisterUnitError> {
+        if self.entries().any(|entry| entry.symbol == symbol) {
+            return Err(RegisterUnitError::DuplicateSymbol(symbol.to_string()));
+        }
+        self.custom.push(UnitTableEntry {
+            family: "custom".to_string(),
+            name: name.to_string(),
+            symbol: symbol.to_string(),
+            dims,
+            amount_of_base,
+        });
+        Ok(())
+    }
+
+    pub fn unit(&self, symbol: &str) -> Option<Unit> {
+        if let Some(unit) = StandardUnit::from_symbol(symbol) {
+            return Some(Unit::Standard(unit));
+        }
+        self.custom
+            .iter()
+            .find(|entry| entry.symbol == symbol)
+            .map(|entry| Unit::Custom {
+                dims: entry.dims,
+                amount_of_base: DisplayValue::from_factor(entry.amount_of_base),
+            })
+    }
+
+    /// Every standard unit in declaration order followed by the custom units.
+    pub fn entries(&self) -> impl Iterator<Item = &UnitTableEntry> {
+        self.standard.iter().chain(&self.custom)
+    }
+
+    pub fn export(&self, format: TableFormat) -> String {
+        let mut out = String::new();
+        match format {
+            TableFormat::Json => self.write_json(&mut out),
+            TableFormat::Csv => self.write_csv(&mut out),
+            TableFormat::Markdown => self.write_markdown(&mut out),
+        }
+        .expect("Writing to a String can't fail!");
+        out
+    }
+
+    fn write_json(&self, out: &mut String) -> fmt::Result {
+        writeln!(out, "[")?;
+        let mut first = true;
+        for entry in self.entries() {
+            if !first {
+                writeln!(out, ",")?;
+            }
+            first = false;
+            let dims = entry.dims;
+            write!(
+                out,
+                "  {{\"family\": {}, \"name\": {}, \"symbol\": {}, \"dims\": {{\"time\": {}, \
+                 \"length\": {}, \"mass\": {}, \"electric_current\": {}, \"temperature\": {}, \
+                 \"amount\": {}, \"luminous_intensity\": {}}}, \"amount_of_base\": \"{}\", \
+                 \"amount_of_base_f64\": {:?}}}",
+                JsonString(&entry.family),
+                JsonString(&entry.name),
+                JsonString(&entry.symbol),
+                dims.time,
+                dims.length,
+                dims.mass,
+                dims.electric_current,
+                dims.temperature,
+                dims.amount,
+                dims.luminous_intensity,
+                entry.amount_of_base,
+                f64::from_factor(entry.amount_of_base),
+            )?;
+        }
+        writeln!(out)?;
+        writeln!(out, "]")
+    }
+
+    fn write_csv(&self, out: &mut String) -> fmt::Result {
+        writeln!(
+            out,
+            "family,name,symbol,time,length,mass,electric_current,temperature,amount,\
+             luminous_intensity,amount_of_base,amount_of_base_f64"
+        )?;
+        for entry in self.entries() {
+            let dims = entry.dims;
+            writeln!(
+                out,
+                "{},{},{},{},{},{},{},{},{},{},{},{:?}",
+                CsvField(&entry.family),
+                CsvField(&entry.name),
+                CsvField(&entry.symbol),
+                dims.time,
+                dims.length,
+                dims.mass,
+                dims.electric_current,
+                dims.temperature,
+                dims.amount,
+                dims.luminous_intensity,
+                entry.amount_of_base,
+                f64::from_factor(entry.amount_of_base),
+            )?;
+        }
+        Ok(())
+    }
+
+    fn write_markdown(&self, out: &mut String) -> fmt::Result {
+        writeln!(
+            out,
+            "| Family | Name | Symbol | Base unit | Amount of base | Approximately |"
+        )?;
+        writeln!(out, "|---|---|---|---|---|---|")?;
+        for entry in self.entries() {
+            writeln!(
+                out,
+                "| {} | {} | {} | {} | {} | {:e} |",
+                MarkdownCell(&entry.family),
+                MarkdownCell(&entry.name),
+                MarkdownCell(&entry.symbol),
+                entry.dims,
+                entry.amount_of_base,
+                f64::from_factor(entry.amount_of_base),
+            )?;
+        }
+        Ok(())
+    }
+}
+impl Default for UnitRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum TableFormat {
+    Json,
+    Csv,
+    Markdown,
+}
+impl FromStr for TableFormat {
+    type Err = ParseTableFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "json" => Ok(TableFormat::Json),
+            "csv" => Ok(TableFormat::Csv),
+            "markdown" | "md" => Ok(TableFormat::Markdown),
+            _ => Err(ParseTableFormatError),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ParseTableFormatError;
+impl fmt::Display for ParseTableFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected one of `json`, `csv` or `markdown`")
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for ParseTableFormatError {}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum RegisterUnitError {
+    DuplicateSymbol(String),
+}
+impl fmt::Display for RegisterUnitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegisterUnitError::DuplicateSymbol(symbol) => {
+                write!(f, "a unit with symbol `{}` already exists", symbol)
+            }
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for RegisterUnitError {}
+
+struct JsonString<'a>(&'a str);
+impl fmt::Display for JsonString<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char('"')?;
+        for char in self.0.chars() {
+            match char {
+                '"' => f.write_str("\\\"")?,
+                '\\' => f.write_str("\\\\")?,
+                '\n' => f.write_str("\\n")?,
+                char if (char as u32) < 0x20 => write!(f, "\\u{:04x}", char as u32)?,
+                char => f.write_char(char)?,
+            }
+        }
+        f.write_char('"')
+    }
+}
+
+struct CsvField<'a>(&'a str);
+impl fmt::Display for CsvField<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.contains(&[',', '"', '\n', '\r'][..]) {
+            write!(f, "\"{}\"", self.0.replace('"', "\"\""))
+        } else {
+            f.write_str(self.0)
+        }
+    }
+}
+
+struct MarkdownCell<'a>(&'a str);
+impl fmt::Display for MarkdownCell<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0.replace('|', "\\|"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::convert::TryFrom;
+
+    use crate::units::*;
+
+    fn registry() -> UnitRegistry {
+        let mut registry = UnitRegistry::new();
+        registry
+            .register(
+                "Furlong, US survey",
+                "fur",
+                UnitDims::LENGTH,
+                Factor::from_decimal("201.168").mul(LengthUnit::METER_FACTOR),
+            )
+            .unwrap();
+        registry
+    }
+
+    #[test]
+    fn registry_test() {
+        let mut registry = registry();
+        assert_eq!(
+            Err(RegisterUnitError::DuplicateSymbol("km".to_string())),
+            registry.register("Klick", "km", UnitDims::LENGTH, Factor::ONE)
+        );
+        assert_eq!(StandardUnit::all().count() + 1, registry.entries().count());
+        assert!(matches!(
+            registry.unit("fur"),
+            Some(Unit::Custom {
+                dims: UnitDims::LENGTH,
+                ..
+            })
+        ));
+        assert!(matches!(
+            registry.unit("km"),
+            Some(Unit::Standard(StandardUnit::Length(LengthUnit::Kilometer)))
+        ));
+        assert!(registry.unit("ls").is_none());
+        assert!(StandardUnit::all().all(|unit| UnitTableEntry::try_from(unit).is_ok()));
+    }
+
+    #[test]
+    fn export_test() {
+        let registry = registry();
+
+        let json = registry.export(TableFormat::Json);
+        assert!(json.starts_with("[\n  {\"family\": \"time\", \"name\": \"Nanosecond\""));
+        assert!(json.contains(
+            "{\"family\": \"mass\", \"name\": \"Oz\", \"symbol\": \"oz\", \"dims\": {\"time\": 0, \
+             \"length\": 0, \"mass\": 1, \"electric_current\": 0, \"temperature\": 0, \
+             \"amount\": 0, \"luminous_intensity\": 0}, \"amount_of_base\": \"56699/2\", \
+             \"amount_of_base_f64\": 28349.5}"
+        ));
+        assert!(json.ends_with("\"amount_of_base_f64\": 201168000000.0}\n]\n"));
+
+        let csv = registry.export(TableFormat::Csv);
+        let mut lines = csv.lines();
+        assert_eq!(
+            Some(
+                "family,name,symbol,time,length,mass,electric_current,temperature,amount,\
+                 luminous_intensity,amount_of_base,amount_of_base_f64"
+            ),
+            lines.next()
+        );
+        assert_eq!(Some("time,Nanosecond,ns,1,0,0,0,0,0,0,1,1.0"), lines.next());
+        assert_eq!(
+            Some(
+                "custom,\"Furlong, US survey\",fur,0,1,0,0,0,0,0,201168000000,\
+                 201168000000.0"
+            ),
+            lines.last()
+        );
+
+        let markdown = registry.export(TableFormat::Markdown);
+        assert!(markdown.contains("| length | Kilometer | km | nm | 1000000000000 | 1e12 |\n"));
+        assert_eq!(registry.entries().count() + 2, markdown.lines().count());
+    }
+
+    #[test]
+    fn format_test() {
+        assert_eq!(Ok(TableFormat::Markdown), "md".parse());
+        assert_eq!(Ok(TableFormat::Json), "JSON".parse());
+        assert_eq!(Err(ParseTableFormatError), "yaml".parse::<TableFormat>());
+    }
+}
@@ -1,8 +1,10 @@
-use std::ops::*;
+use core::fmt;
+use core::marker::ConstParamTy;
+use core::ops::*;
 
-use crate::units::DimsType;
+use crate::units::{DimsType, UnitError};
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, ConstParamTy)]
 pub struct UnitDims {
     pub time: DimsType,
     pub length: DimsType,
@@ -75,14 +77,31 @@ impl UnitDims {
         }
     }
 
+    pub const fn mul(self, rhs: DimsType) -> Self {
+        Self {
+            time: self.time * rhs,
+            length: self.length * rhs,
+            mass: self.mass * rhs,
+            electric_current: self.electric_current * rhs,
+            temperature: self.temperature * rhs,
+            amount: self.amount * rhs,
+            luminous_intensity: self.luminous_intensity * rhs,
+        }
+    }
+
+    /// `==` for const contexts.
+    pub const fn const_eq(self, rhs: Self) -> bool {
+        self.time == rhs.time
+            && self.length == rhs.length
+            && self.mass == rhs.mass
+            && self.electric_current == rhs.electric_current
+            && self.temperature == rhs.temperature
+            && self.amount == rhs.amount
+            && self.luminous_intensity == rhs.luminous_intensity
+    }
+
     pub const fn assert_eq(self, rhs: Self) -> usize{
-        if self.time == rhs.time &&
-            self.length == rhs.length &&
-            self.mass == rhs.mass &&
-            self.electric_current == rhs.electric_current &&
-            self.temperature == rhs.temperature &&
-            self.amount == rhs.amount &&
-            self.luminous_intensity == rhs.luminous_intensity{
+        if self.const_eq(rhs) {
             0
         }
         else{
@@ -90,10 +109,49 @@ impl UnitDims {
         }
     }
 
+    /// The runtime alternative to [`assert_eq`](Self::assert_eq), `self` is the expected dims.
+    pub fn try_eq(self, found: Self) -> Result<(), UnitError> {
+        if self == found {
+            Ok(())
+        } else {
+            Err(UnitError::DimensionMismatch {
+                expected: self,
+                found,
+            })
+        }
+    }
+
     pub const fn convert_for_const(self) -> usize {
         0
     }
 }
+/// Writes the dims in base units, e.g. `nm ns^-1`.
+impl fmt::Display for UnitDims {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let dims = [
+            ("mg", self.mass),
+            ("nm", self.length),
+            ("ns", self.time),
+            ("pA", self.electric_current),
+            ("K", self.temperature),
+            ("mol", self.amount),
+            ("cd", self.luminous_intensity),
+        ];
+        let mut first = true;
+        for (symbol, power) in dims.iter().filter(|(_, power)| *power != 0) {
+            if !first {
+                write!(f, " ")?;
+            }
+            first = false;
+            if *power == 1 {
+                write!(f, "{}", symbol)?;
+            } else {
+                write!(f, "{}^{}", symbol, power)?;
+            }
+        }
+        Ok(())
+    }
+}
 impl Default for UnitDims {
     fn default() -> Self {
         Self::DIMENSIONLESS
@@ -139,15 +197,7 @@ impl Mul<DimsType> for UnitDims {
     type Output = Self;
 
     fn mul(self, rhs: DimsType) -> Self::Output {
-        Self {
-            time: self.time * rhs,
-            length: self.length * rhs,
-            mass: self.mass * rhs,
-            electric_current: self.electric_current * rhs,
-            temperature: self.temperature * rhs,
-            amount: self.amount * rhs,
-            luminous_intensity: self.luminous_intensity * rhs,
-        }
+        self.mul(rhs)
     }
 }
 impl MulAssign<DimsType> for UnitDims {
@@ -0,0 +1,377 @@
+use core::ops::*;
+
+use num_traits::{One, Zero};
+
+use crate::units::{UnitDims, UnitValue, UnitVector, Value};
+
+/// An `N`×`N` matrix in base units whose entries all have dimensions `DIMS`, e.g. an inertia
+/// tensor or a dimensionless rotation.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct UnitMatrix<T, const DIMS: UnitDims, const N: usize>([[T; N]; N])
+where
+    T: Value;
+pub type UnitMatrix3<T, const DIMS: UnitDims> = UnitMatrix<T, DIMS, 3>;
+pub type UnitMatrix4<T, const DIMS: UnitDims> = UnitMatrix<T, DIMS, 4>;
+impl<T, const DIMS: UnitDims, const N: usize> UnitMatrix<T, DIMS, N>
+where
+    T: Value,
+{
+    pub const fn new(rows: [[T; N]; N]) -> Self {
+        Self(rows)
+    }
+
+    pub fn from_diagonal(diagonal: [T; N]) -> Self
+    where
+        T: Copy + Zero,
+    {
+        let mut rows = [[T::zero(); N]; N];
+        for (index, &value) in diagonal.iter().enumerate() {
+            rows[index][index] = value;
+        }
+        Self(rows)
+    }
+
+    pub const fn rows(&self) -> &[[T; N]; N] {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> [[T; N]; N] {
+        self.0
+    }
+
+    /// Panics if `row` or `column` is `>= N`.
+    pub fn get(&self, row: usize, column: usize) -> UnitValue<T, DIMS>
+    where
+        T: Copy,
+    {
+        UnitValue::new(self.0[row][column])
+    }
+
+    pub fn transpose(self) -> Self
+    where
+        T: Copy,
+    {
+        Self(core::array::from_fn(|row| {
+            core::array::from_fn(|column| self.0[column][row])
+        }))
+    }
+}
+impl<T, const N: usize> UnitMatrix<T, { UnitDims::DIMENSIONLESS }, N>
+where
+    T: Value + Copy + Zero + One,
+{
+    pub fn identity() -> Self {
+        Self::from_diagonal([T::one(); N])
+    }
+}
+impl<T, const DIMS: UnitDims> UnitMatrix<T, DIMS, 3>
+where
+    T: Value + Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    pub fn determinant(self) -> UnitValue<T, { DIMS.mul(3) }>
+    where
+        [(); DIMS.mul(3).convert_for_const()]: ,
+    {
+        UnitValue::new(determinant3(&self.0))
+    }
+}
+impl<T, const DIMS: UnitDims> UnitMatrix<T, DIMS, 4>
+where
+    T: Value + Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Laplace expansion along the first row.
+    pub fn determinant(self) -> UnitValue<T, { DIMS.mul(4) }>
+    where
+        [(); DIMS.mul(4).convert_for_const()]: ,
+    {
+        let [first, rest @ ..] = self.0;
+        let cofactor = |column: usize| {
+            first[column]
+                * determinant3(&rest.map(|row| {
+                    let mut minor = [row[0]; 3];
+                    for (index, &value) in row.iter().enumerate().filter(|(i, _)| *i != column) {
+                        minor[index - (index > column) as usize] = value;
+                    }
+                    minor
+                }))
+        };
+        UnitValue::new(cofactor(0) - cofactor(1) + cofactor(2) - cofactor(3))
+    }
+}
+impl<const DIMS: UnitDims, const N: usize> UnitMatrix<f64, DIMS, N> {
+    /// Gauss-Jordan elimination with partial pivoting, [`None`] if the matrix is singular, i.e.
+    /// a pivot is within rounding error of zero relative to the largest entry of its row.
+    pub fn inverse(self) -> Option<UnitMatrix<f64, { UnitDims::DIMENSIONLESS.sub(DIMS) }, N>>
+    where
+        [(); UnitDims::DIMENSIONLESS.sub(DIMS).convert_for_const()]: ,
+    {
+        // Each row's largest entry, so rows of very different scale are judged on their own.
+        let mut row_scales = self
+            .0
+            .map(|row| row.iter().fold(0.0, |max: f64, value| max.max(value.abs())));
+        let mut rows = self.0;
+        let mut inverse = UnitMatrix::<f64, { UnitDims::DIMENSIONLESS }, N>::identity().0;
+        for column in 0..N {
+            let pivot = (column..N).fold(column, |best, row| {
+                if rows[row][column].abs() > rows[best][column].abs() {
+                    row
+                } else {
+                    best
+                }
+            });
+            let value = rows[pivot][column];
+            if !value.is_finite() || value.abs() <= N as f64 * f64::EPSILON * row_scales[pivot] {
+                return None;
+            }
+            rows.swap(column, pivot);
+            row_scales.swap(column, pivot);
+            inverse.swap(column, pivot);
+
+            let scale = rows[column][column];
+            for index in 0..N {
+                rows[column][index] /= scale;
+                inverse[column][index] /= scale;
+            }
+            for row in (0..N).filter(|&row| row != column) {
+                let factor = rows[row][column];
+                for index in 0..N {
+                    rows[row][index] -= factor * rows[column][index];
+                    inverse[row][index] -= factor * inverse[column][index];
+                }
+            }
+        }
+        Some(UnitMatrix(inverse))
+    }
+}
+
+fn determinant3<T>(m: &[[T; 3]; 3]) -> T
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+}
+
+/// Sums of products of `T`, the inner loop of matrix products.
+fn dot<T, const N: usize>(row: &[T; N], column: impl Fn(usize) -> T) -> T
+where
+    T: Copy + Zero + Mul<Output = T>,
+{
+    row.iter()
+        .enumerate()
+        .fold(T::zero(), |sum, (index, &value)| {
+            sum + value * column(index)
+        })
+}
+
+impl<T, const DIMS: UnitDims, const N: usize> Add for UnitMatrix<T, DIMS, N>
+where
+    T: Value + Copy + Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(core::array::from_fn(|row| {
+            core::array::from_fn(|column| self.0[row][column] + rhs.0[row][column])
+        }))
+    }
+}
+impl<T, const DIMS: UnitDims, const N: usize> Sub for UnitMatrix<T, DIMS, N>
+where
+    T: Value + Copy + Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(core::array::from_fn(|row| {
+            core::array::from_fn(|column| self.0[row][column] - rhs.0[row][column])
+        }))
+    }
+}
+impl<T, const DIMS: UnitDims, const N: usize> Neg for UnitMatrix<T, DIMS, N>
+where
+    T: Value + Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(self.0.map(|row| row.map(Neg::neg)))
+    }
+}
+impl<T, const DIMS: UnitDims, const N: usize> Mul<T> for UnitMatrix<T, DIMS, N>
+where
+    T: Value + Copy + Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self(self.0.map(|row| row.map(|value| value * rhs)))
+    }
+}
+impl<T, const DIMS_L: UnitDims, const DIMS_R: UnitDims, const N: usize> Mul<UnitValue<T, DIMS_R>>
+    for UnitMatrix<T, DIMS_L, N>
+where
+    T: Value + Copy + Mul<Output = T>,
+    [(); DIMS_L.add(DIMS_R).convert_for_const()]: ,
+{
+    type Output = UnitMatrix<T, { DIMS_L.add(DIMS_R) }, N>;
+
+    fn mul(self, rhs: UnitValue<T, DIMS_R>) -> Self::Output {
+        let rhs = rhs.into_inner();
+        UnitMatrix(self.0.map(|row| row.map(|value| value * rhs)))
+    }
+}
+impl<T, const DIMS_L: UnitDims, const DIMS_R: UnitDims, const N: usize>
+    Mul<UnitVector<T, DIMS_R, N>> for UnitMatrix<T, DIMS_L, N>
+where
+    T: Value + Copy + Zero + Mul<Output = T>,
+    [(); DIMS_L.add(DIMS_R).convert_for_const()]: ,
+{
+    type Output = UnitVector<T, { DIMS_L.add(DIMS_R) }, N>;
+
+    fn mul(self, rhs: UnitVector<T, DIMS_R, N>) -> Self::Output {
+        let rhs = rhs.components();
+        UnitVector::new(self.0.map(|row| dot(&row, |index| rhs[index])))
+    }
+}
+impl<T, const DIMS_L: UnitDims, const DIMS_R: UnitDims, const N: usize>
+    Mul<UnitMatrix<T, DIMS_R, N>> for UnitMatrix<T, DIMS_L, N>
+where
+    T: Value + Copy + Zero + Mul<Output = T>,
+    [(); DIMS_L.add(DIMS_R).convert_for_const()]: ,
+{
+    type Output = UnitMatrix<T, { DIMS_L.add(DIMS_R) }, N>;
+
+    fn mul(self, rhs: UnitMatrix<T, DIMS_R, N>) -> Self::Output {
+        UnitMatrix(
+            self.0
+                .map(|row| core::array::from_fn(|column| dot(&row, |index| rhs.0[index][column]))),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::units::*;
+
+    fn assert_close<const N: usize>(expected: [[f64; N]; N], actual: [[f64; N]; N]) {
+        for (expected_row, actual_row) in expected.iter().zip(actual.iter()) {
+            for (expected, actual) in expected_row.iter().zip(actual_row) {
+                assert!(
+                    (expected - actual).abs() < 1e-12,
+                    "expected {:?}, got {:?}",
+                    expected_row,
+                    actual_row
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn product_test() {
+        // A solid cube's inertia tensor times its spin gives its angular momentum.
+        let inertia = UnitMatrix3::<f64, { UnitDims::MASS.add(UnitDims::AREA) }>::from_diagonal([
+            2.0, 2.0, 4.0,
+        ]);
+        let spin = UnitVector3::<f64, { UnitDims::FREQUENCY }>::new([0.0, 1.0, 3.0]);
+        let momentum: UnitVector3<f64, { UnitDims::ACTION }> = inertia * spin;
+        assert_eq!([0.0, 2.0, 12.0], momentum.into_inner());
+        assert_eq!(UnitValue::new(4.0), inertia.get(2, 2));
+
+        // A quarter turn about z.
+        let rotation = UnitMatrix3::<f64, { UnitDims::DIMENSIONLESS }>::new([
+            [0.0, -1.0, 0.0],
+            [1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0],
+        ]);
+        let position = UnitVector3::<f64, { UnitDims::LENGTH }>::new([1.0, 2.0, 3.0]);
+        let rotated: UnitVector3<f64, { UnitDims::LENGTH }> = rotation * position;
+        assert_eq!([-2.0, 1.0, 3.0], rotated.into_inner());
+        let back: UnitMatrix3<f64, { UnitDims::DIMENSIONLESS }> = rotation.transpose() * rotation;
+        assert_eq!(UnitMatrix3::identity(), back);
+
+        let time: Time<f64> = UnitValue::new(2.0);
+        let scaled: UnitMatrix3<f64, { UnitDims::ENERGY }> =
+            inertia * (UnitValue::<f64, { UnitDims::FREQUENCY }>::new(1.0) / time);
+        assert_eq!(1.0, *scaled.get(0, 0).value());
+        assert_eq!([[0.0; 3]; 3], (inertia - inertia).into_inner());
+        assert_eq!(
+            (inertia * 2.0).into_inner(),
+            (inertia + inertia).into_inner()
+        );
+        assert_eq!(-2.0, *(-inertia).get(1, 1).value());
+    }
+
+    #[test]
+    fn determinant_test() {
+        let lengths = UnitMatrix3::<f64, { UnitDims::LENGTH }>::new([
+            [2.0, 0.0, 1.0],
+            [1.0, 3.0, 0.0],
+            [0.0, 1.0, 4.0],
+        ]);
+        let volume: Volume<f64> = lengths.determinant();
+        assert_eq!(25.0, volume.into_inner());
+
+        let matrix = UnitMatrix4::<i64, { UnitDims::TIME }>::new([
+            [1, 0, 2, -1],
+            [3, 0, 0, 5],
+            [2, 1, 4, -3],
+            [1, 0, 5, 0],
+        ]);
+        let det: UnitValue<i64, { UnitDims::TIME.mul(4) }> = matrix.determinant();
+        assert_eq!(30, det.into_inner());
+    }
+
+    #[test]
+    fn inverse_test() {
+        let lengths = UnitMatrix3::<f64, { UnitDims::LENGTH }>::new([
+            [2.0, 0.0, 1.0],
+            [1.0, 3.0, 0.0],
+            [0.0, 1.0, 4.0],
+        ]);
+        let inverse: UnitMatrix3<f64, { UnitDims::LENGTH.mul(-1) }> = lengths.inverse().unwrap();
+        let product: UnitMatrix3<f64, { UnitDims::DIMENSIONLESS }> = lengths * inverse;
+        assert_close(UnitMatrix3::identity().into_inner(), product.into_inner());
+
+        let matrix = UnitMatrix4::<f64, { UnitDims::MASS }>::new([
+            [0.0, 2.0, 0.0, 1.0],
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 3.0, 0.0],
+            [0.0, 1.0, 0.0, 1.0],
+        ]);
+        let product: UnitMatrix4<f64, { UnitDims::DIMENSIONLESS }> =
+            matrix.inverse().unwrap() * matrix;
+        assert_close(UnitMatrix4::identity().into_inner(), product.into_inner());
+
+        let singular = UnitMatrix3::<f64, { UnitDims::MASS }>::new([
+            [1.0, 2.0, 3.0],
+            [2.0, 4.0, 6.0],
+            [0.0, 1.0, 1.0],
+        ]);
+        assert_eq!(None, singular.inverse());
+        // Singular, but elimination leaves a rounding error rather than an exact zero pivot.
+        let rounded = UnitMatrix3::<f64, { UnitDims::MASS }>::new([
+            [1e30, 2e30, 3e30],
+            [4e30, 5e30, 6e30],
+            [7e30, 8e30, 9e30],
+        ]);
+        assert_eq!(None, rounded.inverse());
+        let small: UnitMatrix3<f64, { UnitDims::MASS }> =
+            UnitMatrix3::from_diagonal([1e-30, 2e-30, 4e-30]);
+        let product: UnitMatrix3<f64, { UnitDims::DIMENSIONLESS }> =
+            small.inverse().unwrap() * small;
+        assert_close(UnitMatrix3::identity().into_inner(), product.into_inner());
+        // Rows of very different scale are each judged against their own entries.
+        let mixed: UnitMatrix<f64, { UnitDims::MASS }, 2> =
+            UnitMatrix::from_diagonal([1.0, 1e-17]);
+        let product: UnitMatrix<f64, { UnitDims::DIMENSIONLESS }, 2> =
+            mixed.inverse().unwrap() * mixed;
+        assert_close(UnitMatrix::identity().into_inner(), product.into_inner());
+        let mixed: UnitMatrix3<f64, { UnitDims::MASS }> =
+            UnitMatrix3::from_diagonal([1e30, 1.0, 1.0]);
+        let product: UnitMatrix3<f64, { UnitDims::DIMENSIONLESS }> =
+            mixed.inverse().unwrap() * mixed;
+        assert_close(UnitMatrix3::identity().into_inner(), product.into_inner());
+    }
+}
@@ -0,0 +1,149 @@
+use core::ops::*;
+
+use num_traits::Zero;
+
+use crate::units::{UnitDims, UnitValue, Value};
+
+/// An absolute point such as a position, an absolute temperature or an instant, stored as the
+/// offset from an origin picked by the caller.
+///
+/// Subtracting two points gives a [`UnitValue`] delta and a delta can be added to a point, but
+/// points can't be added to each other.
+///
+/// ```compile_fail,E0308
+/// # #![feature(adt_const_params)]
+/// # #![allow(incomplete_features)]
+/// # use space_rpg::units::{UnitDims, UnitPoint, UnitValue};
+/// let a = UnitPoint::<i64, { UnitDims::LENGTH }>::new(UnitValue::new(1));
+/// let b = UnitPoint::<i64, { UnitDims::LENGTH }>::new(UnitValue::new(2));
+/// let _ = a + b;
+/// ```
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub struct UnitPoint<T, const DIMS: UnitDims>(UnitValue<T, DIMS>)
+where
+    T: Value;
+impl<T, const DIMS: UnitDims> UnitPoint<T, DIMS>
+where
+    T: Value,
+{
+    pub const fn new(offset: UnitValue<T, DIMS>) -> Self {
+        Self(offset)
+    }
+
+    pub fn origin() -> Self
+    where
+        T: Zero,
+    {
+        Self(UnitValue::new(T::zero()))
+    }
+
+    /// The offset from the origin.
+    pub const fn offset(&self) -> &UnitValue<T, DIMS> {
+        &self.0
+    }
+
+    pub fn into_offset(self) -> UnitValue<T, DIMS> {
+        self.0
+    }
+}
+impl<TL, TR, const DIMS: UnitDims> Add<UnitValue<TR, DIMS>> for UnitPoint<TL, DIMS>
+where
+    TL: Value,
+    TR: Value,
+    TL: Add<TR>,
+    TL::Output: Value,
+{
+    type Output = UnitPoint<TL::Output, DIMS>;
+
+    fn add(self, rhs: UnitValue<TR, DIMS>) -> Self::Output {
+        UnitPoint(self.0 + rhs)
+    }
+}
+impl<TL, TR, const DIMS: UnitDims> Add<UnitPoint<TR, DIMS>> for UnitValue<TL, DIMS>
+where
+    TL: Value,
+    TR: Value,
+    TL: Add<TR>,
+    TL::Output: Value,
+{
+    type Output = UnitPoint<TL::Output, DIMS>;
+
+    fn add(self, rhs: UnitPoint<TR, DIMS>) -> Self::Output {
+        UnitPoint(self + rhs.0)
+    }
+}
+impl<TL, TR, const DIMS: UnitDims> AddAssign<UnitValue<TR, DIMS>> for UnitPoint<TL, DIMS>
+where
+    TL: Value,
+    TR: Value,
+    TL: AddAssign<TR>,
+{
+    fn add_assign(&mut self, rhs: UnitValue<TR, DIMS>) {
+        self.0 += rhs
+    }
+}
+impl<TL, TR, const DIMS: UnitDims> Sub<UnitValue<TR, DIMS>> for UnitPoint<TL, DIMS>
+where
+    TL: Value,
+    TR: Value,
+    TL: Sub<TR>,
+    TL::Output: Value,
+{
+    type Output = UnitPoint<TL::Output, DIMS>;
+
+    fn sub(self, rhs: UnitValue<TR, DIMS>) -> Self::Output {
+        UnitPoint(self.0 - rhs)
+    }
+}
+impl<TL, TR, const DIMS: UnitDims> SubAssign<UnitValue<TR, DIMS>> for UnitPoint<TL, DIMS>
+where
+    TL: Value,
+    TR: Value,
+    TL: SubAssign<TR>,
+{
+    fn sub_assign(&mut self, rhs: UnitValue<TR, DIMS>) {
+        self.0 -= rhs
+    }
+}
+impl<TL, TR, const DIMS: UnitDims> Sub<UnitPoint<TR, DIMS>> for UnitPoint<TL, DIMS>
+where
+    TL: Value,
+    TR: Value,
+    TL: Sub<TR>,
+    TL::Output: Value,
+{
+    type Output = UnitValue<TL::Output, DIMS>;
+
+    fn sub(self, rhs: UnitPoint<TR, DIMS>) -> Self::Output {
+        self.0 - rhs.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::qty;
+    use crate::units::*;
+
+    #[test]
+    fn point_test() {
+        let start = UnitPoint::<f64, { UnitDims::LENGTH }>::new(qty!(2 km));
+        let end = start + qty!(500 m);
+        assert_eq!(qty!(500 m), end - start);
+        assert_eq!(start, end - (end - start));
+        assert_eq!(end, qty!(500 m) + start);
+        assert!(end > start);
+
+        let mut position = UnitPoint::<f64, { UnitDims::LENGTH }>::origin();
+        position += qty!(1 km);
+        position -= qty!(250 m);
+        assert_eq!(qty!(750 m), *position.offset());
+    }
+
+    #[test]
+    fn temperature_test() {
+        let freezing = UnitPoint::<f64, { UnitDims::TEMPERATURE }>::new(UnitValue::new(273.15));
+        let boiling = freezing + UnitValue::new(100.0);
+        assert_eq!(373.15, boiling.into_offset().into_inner());
+        assert_eq!(100.0, (boiling - freezing).into_inner());
+    }
+}
@@ -1,18 +1,37 @@
-use std::ops::*;
+use core::convert::TryFrom;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::*;
 
-use crate::units::{DisplayValue, StandardUnitImpl, Value};
+use num_bigint::BigInt;
+
+use crate::units::{
+    DisplayNumber, DisplayValue, ExactValue, Generic, KindTag, StandardUnitImpl, UnitError, Value,
+};
 use crate::units::unit_dims::UnitDims;
 
-#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
-pub struct UnitValue<T, const DIMS: UnitDims>(T)
+/// A value in base units with dimensions `DIMS`, tagged with the kind `K` to tell apart
+/// quantities that share dimensions (see [`kinds`](crate::units::kinds)).
+#[derive(Copy, Clone, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub struct UnitValue<T, const DIMS: UnitDims, K = Generic>(T, PhantomData<K>)
 where
-    T: Value;
-impl<T, const DIMS: UnitDims> UnitValue<T, DIMS>
+    T: Value,
+    K: KindTag;
+impl<T, const DIMS: UnitDims, K> UnitValue<T, DIMS, K>
 where
     T: Value,
+    K: KindTag,
 {
-    pub fn new(value: T) -> Self{
-        Self(value)
+    /// Fails to compile when `K` is a kind of other dims than `DIMS`.
+    const KIND_MATCHES: () = assert!(
+        K::ANY_DIMS || K::DIMS.const_eq(DIMS),
+        "the kind doesn't match the dims"
+    );
+
+    pub const fn new(value: T) -> Self{
+        #[allow(clippy::let_unit_value)]
+        let () = Self::KIND_MATCHES;
+        Self(value, PhantomData)
     }
 
     pub const fn value(&self) -> &T {
@@ -27,6 +46,19 @@ where
         self.0
     }
 
+    /// Retags the value as kind `K2`, e.g. an energy as a torque. Fails to compile when `K2` is
+    /// a kind of other dims.
+    pub fn with_kind<K2>(self) -> UnitValue<T, DIMS, K2>
+    where
+        K2: KindTag,
+    {
+        UnitValue::new(self.0)
+    }
+
+    pub fn into_generic(self) -> UnitValue<T, DIMS> {
+        self.with_kind()
+    }
+
     pub fn into_standard_unit<SU>(self, unit: SU) -> DisplayValue
     where
         DisplayValue: From<T>,
@@ -34,61 +66,135 @@ where
     {
         unit.display_repr(self.0)
     }
+
+    /// Like [`into_standard_unit`](Self::into_standard_unit) with any [`DisplayNumber`] backend.
+    pub fn into_standard_unit_as<D, SU>(self, unit: SU) -> D
+    where
+        D: DisplayNumber + From<T>,
+        SU: StandardUnitImpl<DIMS>,
+    {
+        unit.display_repr_as(self.0)
+    }
+
+    /// Returns [`None`] if the value in base units is not representable by `T`.
+    pub fn from_standard_unit_exact<SU>(display_value: &ExactValue, unit: SU) -> Option<Self>
+    where
+        T: TryFrom<BigInt>,
+        SU: StandardUnitImpl<DIMS>,
+    {
+        let base_value = unit.exact_base_repr(display_value);
+        if !base_value.is_integer() {
+            return None;
+        }
+        T::try_from(base_value.to_integer()).ok().map(Self::new)
+    }
+
+    /// Like [`from_standard_unit_exact`](Self::from_standard_unit_exact) but tells a fractional
+    /// base value apart from one that doesn't fit in `T`.
+    pub fn try_from_standard_unit_exact<SU>(
+        display_value: &ExactValue,
+        unit: SU,
+    ) -> Result<Self, UnitError>
+    where
+        T: TryFrom<BigInt>,
+        SU: StandardUnitImpl<DIMS>,
+    {
+        let base_value = unit.exact_base_repr(display_value);
+        if !base_value.is_integer() {
+            return Err(UnitError::PrecisionLoss);
+        }
+        T::try_from(base_value.to_integer())
+            .map(Self::new)
+            .map_err(|_| UnitError::Overflow)
+    }
+
+    pub fn into_standard_unit_exact<SU>(self, unit: SU) -> ExactValue
+    where
+        T: Into<BigInt>,
+        SU: StandardUnitImpl<DIMS>,
+    {
+        unit.exact_display_repr(&ExactValue::from_integer(self.0.into()))
+    }
 }
-impl<T, const DIMS: UnitDims> From<T> for UnitValue<T, DIMS> where T: Value{
+impl<T, const DIMS: UnitDims, K> From<T> for UnitValue<T, DIMS, K>
+where
+    T: Value,
+    K: KindTag,
+{
     fn from(from: T) -> Self {
-        Self(from)
+        Self::new(from)
+    }
+}
+/// Generic values print as `UnitValue(value)`, tagged ones as `UnitValue(value, kind)`.
+impl<T, const DIMS: UnitDims, K> fmt::Debug for UnitValue<T, DIMS, K>
+where
+    T: Value + fmt::Debug,
+    K: KindTag,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut tuple = f.debug_tuple("UnitValue");
+        tuple.field(&self.0);
+        if K::NAME != Generic::NAME {
+            tuple.field(&K::NAME);
+        }
+        tuple.finish()
     }
 }
-impl<TL, TR, const DIMS: UnitDims> Add<UnitValue<TR, DIMS>> for UnitValue<TL, DIMS>
+impl<TL, TR, const DIMS: UnitDims, K> Add<UnitValue<TR, DIMS, K>> for UnitValue<TL, DIMS, K>
 where
+    K: KindTag,
     TL: Value,
     TR: Value,
     TL: Add<TR>,
     TL::Output: Value,
 {
-    type Output = UnitValue<TL::Output, DIMS>;
+    type Output = UnitValue<TL::Output, DIMS, K>;
 
-    fn add(self, rhs: UnitValue<TR, DIMS>) -> Self::Output {
-        UnitValue(self.0 + rhs.0)
+    fn add(self, rhs: UnitValue<TR, DIMS, K>) -> Self::Output {
+        UnitValue::new(self.0 + rhs.0)
     }
 }
-impl<TL, TR, const DIMS: UnitDims> AddAssign<UnitValue<TR, DIMS>> for UnitValue<TL, DIMS>
+impl<TL, TR, const DIMS: UnitDims, K> AddAssign<UnitValue<TR, DIMS, K>> for UnitValue<TL, DIMS, K>
 where
+    K: KindTag,
     TL: Value,
     TR: Value,
     TL: AddAssign<TR>,
 {
-    fn add_assign(&mut self, rhs: UnitValue<TR, DIMS>) {
+    fn add_assign(&mut self, rhs: UnitValue<TR, DIMS, K>) {
         self.0 += rhs.0
     }
 }
-impl<TL, TR, const DIMS: UnitDims> Sub<UnitValue<TR, DIMS>> for UnitValue<TL, DIMS>
+impl<TL, TR, const DIMS: UnitDims, K> Sub<UnitValue<TR, DIMS, K>> for UnitValue<TL, DIMS, K>
 where
+    K: KindTag,
     TL: Value,
     TR: Value,
     TL: Sub<TR>,
     TL::Output: Value,
 {
-    type Output = UnitValue<TL::Output, DIMS>;
+    type Output = UnitValue<TL::Output, DIMS, K>;
 
-    fn sub(self, rhs: UnitValue<TR, DIMS>) -> Self::Output {
-        UnitValue(self.0 - rhs.0)
+    fn sub(self, rhs: UnitValue<TR, DIMS, K>) -> Self::Output {
+        UnitValue::new(self.0 - rhs.0)
     }
 }
-impl<TL, TR, const DIMS: UnitDims> SubAssign<UnitValue<TR, DIMS>> for UnitValue<TL, DIMS>
+impl<TL, TR, const DIMS: UnitDims, K> SubAssign<UnitValue<TR, DIMS, K>> for UnitValue<TL, DIMS, K>
 where
+    K: KindTag,
     TL: Value,
     TR: Value,
     TL: SubAssign<TR>,
 {
-    fn sub_assign(&mut self, rhs: UnitValue<TR, DIMS>) {
+    fn sub_assign(&mut self, rhs: UnitValue<TR, DIMS, K>) {
         self.0 -= rhs.0
     }
 }
-impl<TL, TR, const DIMS_L: UnitDims, const DIMS_R: UnitDims> Mul<UnitValue<TR, DIMS_R>>
-    for UnitValue<TL, DIMS_L>
+impl<TL, TR, const DIMS_L: UnitDims, const DIMS_R: UnitDims, KL, KR> Mul<UnitValue<TR, DIMS_R, KR>>
+    for UnitValue<TL, DIMS_L, KL>
 where
+    KL: KindTag,
+    KR: KindTag,
     TL: Value,
     TR: Value,
     TL: Mul<TR>,
@@ -97,26 +203,28 @@ where
 {
     type Output = UnitValue<TL::Output, { DIMS_L.add(DIMS_R) }>;
 
-    fn mul(self, rhs: UnitValue<TR, DIMS_R>) -> Self::Output {
-        UnitValue(self.0 * rhs.0)
+    fn mul(self, rhs: UnitValue<TR, DIMS_R, KR>) -> Self::Output {
+        UnitValue::new(self.0 * rhs.0)
     }
 }
-impl<TL, TR, const DIMS: UnitDims> Mul<TR> for UnitValue<TL, DIMS>
+impl<TL, TR, const DIMS: UnitDims, K> Mul<TR> for UnitValue<TL, DIMS, K>
 where
+    K: KindTag,
     TL: Value,
     TR: Value,
     TL: Mul<TR>,
     TL::Output: Value,
 {
-    type Output = UnitValue<TL::Output, DIMS>;
+    type Output = UnitValue<TL::Output, DIMS, K>;
 
     fn mul(self, rhs: TR) -> Self::Output {
-        UnitValue(self.0 * rhs)
+        UnitValue::new(self.0 * rhs)
     }
 }
-impl<TL, TR, const DIMS: UnitDims> MulAssign<UnitValue<TR, { UnitDims::DIMENSIONLESS }>>
-    for UnitValue<TL, DIMS>
+impl<TL, TR, const DIMS: UnitDims, K> MulAssign<UnitValue<TR, { UnitDims::DIMENSIONLESS }>>
+    for UnitValue<TL, DIMS, K>
 where
+    K: KindTag,
     TL: Value,
     TR: Value,
     TL: MulAssign<TR>,
@@ -125,8 +233,9 @@ where
         self.0 *= rhs.0;
     }
 }
-impl<TL, TR, const DIMS: UnitDims> MulAssign<TR> for UnitValue<TL, DIMS>
+impl<TL, TR, const DIMS: UnitDims, K> MulAssign<TR> for UnitValue<TL, DIMS, K>
 where
+    K: KindTag,
     TL: Value,
     TR: Value,
     TL: MulAssign<TR>,
@@ -135,9 +244,11 @@ where
         self.0 *= rhs;
     }
 }
-impl<TL, TR, const DIMS_L: UnitDims, const DIMS_R: UnitDims> Div<UnitValue<TR, DIMS_R>>
-    for UnitValue<TL, DIMS_L>
+impl<TL, TR, const DIMS_L: UnitDims, const DIMS_R: UnitDims, KL, KR> Div<UnitValue<TR, DIMS_R, KR>>
+    for UnitValue<TL, DIMS_L, KL>
 where
+    KL: KindTag,
+    KR: KindTag,
     TL: Value,
     TR: Value,
     TL: Div<TR>,
@@ -146,26 +257,28 @@ where
 {
     type Output = UnitValue<TL::Output, { DIMS_L.sub(DIMS_R) }>;
 
-    fn div(self, rhs: UnitValue<TR, DIMS_R>) -> Self::Output {
-        UnitValue(self.0 / rhs.0)
+    fn div(self, rhs: UnitValue<TR, DIMS_R, KR>) -> Self::Output {
+        UnitValue::new(self.0 / rhs.0)
     }
 }
-impl<TL, TR, const DIMS: UnitDims> Div<TR> for UnitValue<TL, DIMS>
+impl<TL, TR, const DIMS: UnitDims, K> Div<TR> for UnitValue<TL, DIMS, K>
 where
+    K: KindTag,
     TL: Value,
     TR: Value,
     TL: Div<TR>,
     TL::Output: Value,
 {
-    type Output = UnitValue<TL::Output, DIMS>;
+    type Output = UnitValue<TL::Output, DIMS, K>;
 
     fn div(self, rhs: TR) -> Self::Output {
-        UnitValue(self.0 / rhs)
+        UnitValue::new(self.0 / rhs)
     }
 }
-impl<TL, TR, const DIMS: UnitDims> DivAssign<UnitValue<TR, { UnitDims::DIMENSIONLESS }>>
-    for UnitValue<TL, DIMS>
+impl<TL, TR, const DIMS: UnitDims, K> DivAssign<UnitValue<TR, { UnitDims::DIMENSIONLESS }>>
+    for UnitValue<TL, DIMS, K>
 where
+    K: KindTag,
     TL: Value,
     TR: Value,
     TL: DivAssign<TR>,
@@ -174,8 +287,9 @@ where
         self.0 /= rhs.0;
     }
 }
-impl<TL, TR, const DIMS: UnitDims> DivAssign<TR> for UnitValue<TL, DIMS>
+impl<TL, TR, const DIMS: UnitDims, K> DivAssign<TR> for UnitValue<TL, DIMS, K>
 where
+    K: KindTag,
     TL: Value,
     TR: Value,
     TL: DivAssign<TR>,
@@ -187,10 +301,14 @@ where
 
 #[cfg(test)]
 mod test{
+    use alloc::format;
+
     use rand::random;
 
     use crate::units::unit_dims::UnitDims;
-    use crate::units::UnitValue;
+    use crate::units::{
+        ExactValue, Force, Length, Radioactivity, TimeUnit, Torque, UnitError, UnitValue,
+    };
 
     #[test]
     fn value_test(){
@@ -208,4 +326,41 @@ mod test{
         assert_eq!(internal_value, *value.value_mut());
         assert_eq!(internal_value, value.into_inner());
     }
+
+    #[test]
+    fn try_exact_test(){
+        let half = ExactValue::new(1.into(), 2.into());
+        assert_eq!(
+            Ok(UnitValue::<u64, { UnitDims::TIME }>::new(500_000)),
+            UnitValue::try_from_standard_unit_exact(&half, TimeUnit::Millisecond)
+        );
+        assert_eq!(
+            Err(UnitError::PrecisionLoss),
+            UnitValue::<u64, { UnitDims::TIME }>::try_from_standard_unit_exact(&half, TimeUnit::Nanosecond)
+        );
+        assert_eq!(
+            Err(UnitError::Overflow),
+            UnitValue::<u8, { UnitDims::TIME }>::try_from_standard_unit_exact(&half, TimeUnit::Second)
+        );
+        assert_eq!(
+            Err(UnitError::DimensionMismatch { expected: UnitDims::TIME, found: UnitDims::LENGTH }),
+            UnitDims::TIME.try_eq(UnitDims::LENGTH)
+        );
+    }
+
+    #[test]
+    fn kind_test(){
+        let force: Force<f64> = UnitValue::new(2.0);
+        let arm: Length<f64> = UnitValue::new(3.0);
+        let mut torque: Torque<f64> = (force * arm).with_kind();
+        torque += Torque::new(1.0);
+        torque *= 2.0;
+        assert_eq!(14.0, torque.into_inner());
+        assert_eq!(UnitValue::new(14.0), torque.into_generic() - UnitValue::new(0.0));
+        assert_eq!("UnitValue(14.0, \"torque\")", format!("{:?}", torque));
+        assert_eq!("UnitValue(2.0)", format!("{:?}", force));
+
+        let decays: Radioactivity<f64> = UnitValue::new(5.0);
+        assert_eq!(10.0, (decays * UnitValue::<f64, { UnitDims::TIME }>::new(2.0)).into_inner());
+    }
 }
@@ -0,0 +1,220 @@
+use core::ops::*;
+
+#[cfg(feature = "std")]
+use num_traits::Float;
+use num_traits::Zero;
+
+use crate::units::{UnitDims, UnitValue, Value};
+
+/// `N` components in base units that all have dimensions `DIMS`, e.g. a position or velocity.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct UnitVector<T, const DIMS: UnitDims, const N: usize>([T; N])
+where
+    T: Value;
+pub type UnitVector2<T, const DIMS: UnitDims> = UnitVector<T, DIMS, 2>;
+pub type UnitVector3<T, const DIMS: UnitDims> = UnitVector<T, DIMS, 3>;
+impl<T, const DIMS: UnitDims, const N: usize> UnitVector<T, DIMS, N>
+where
+    T: Value,
+{
+    pub const fn new(components: [T; N]) -> Self {
+        Self(components)
+    }
+
+    pub fn from_values(values: [UnitValue<T, DIMS>; N]) -> Self {
+        Self(values.map(UnitValue::into_inner))
+    }
+
+    pub fn zero() -> Self
+    where
+        T: Zero,
+    {
+        Self(core::array::from_fn(|_| T::zero()))
+    }
+
+    pub const fn components(&self) -> &[T; N] {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> [T; N] {
+        self.0
+    }
+
+    pub fn into_values(self) -> [UnitValue<T, DIMS>; N] {
+        self.0.map(UnitValue::new)
+    }
+
+    /// Panics if `index >= N`.
+    pub fn get(&self, index: usize) -> UnitValue<T, DIMS>
+    where
+        T: Copy,
+    {
+        UnitValue::new(self.0[index])
+    }
+
+    pub fn dot<const DIMS_R: UnitDims>(
+        self,
+        rhs: UnitVector<T, DIMS_R, N>,
+    ) -> UnitValue<T, { DIMS.add(DIMS_R) }>
+    where
+        T: Copy + Zero + Mul<Output = T>,
+        [(); DIMS.add(DIMS_R).convert_for_const()]: ,
+    {
+        UnitValue::new(
+            self.0
+                .iter()
+                .zip(rhs.0.iter())
+                .fold(T::zero(), |sum, (&l, &r)| sum + l * r),
+        )
+    }
+
+    /// The euclidean length.
+    #[cfg(feature = "std")]
+    pub fn norm(self) -> UnitValue<T, DIMS>
+    where
+        T: Float,
+    {
+        UnitValue::new(self.0.iter().fold(T::zero(), |sum, &c| sum + c * c).sqrt())
+    }
+}
+impl<T, const DIMS: UnitDims> UnitVector<T, DIMS, 3>
+where
+    T: Value + Copy + Mul<Output = T> + Sub<Output = T>,
+{
+    pub fn cross<const DIMS_R: UnitDims>(
+        self,
+        rhs: UnitVector<T, DIMS_R, 3>,
+    ) -> UnitVector<T, { DIMS.add(DIMS_R) }, 3>
+    where
+        [(); DIMS.add(DIMS_R).convert_for_const()]: ,
+    {
+        let [lx, ly, lz] = self.0;
+        let [rx, ry, rz] = rhs.0;
+        UnitVector([ly * rz - lz * ry, lz * rx - lx * rz, lx * ry - ly * rx])
+    }
+}
+impl<T, const DIMS: UnitDims, const N: usize> Add for UnitVector<T, DIMS, N>
+where
+    T: Value + Copy + Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(core::array::from_fn(|index| self.0[index] + rhs.0[index]))
+    }
+}
+impl<T, const DIMS: UnitDims, const N: usize> AddAssign for UnitVector<T, DIMS, N>
+where
+    T: Value + AddAssign,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        for (l, r) in self.0.iter_mut().zip(rhs.0) {
+            *l += r;
+        }
+    }
+}
+impl<T, const DIMS: UnitDims, const N: usize> Sub for UnitVector<T, DIMS, N>
+where
+    T: Value + Copy + Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(core::array::from_fn(|index| self.0[index] - rhs.0[index]))
+    }
+}
+impl<T, const DIMS: UnitDims, const N: usize> SubAssign for UnitVector<T, DIMS, N>
+where
+    T: Value + SubAssign,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        for (l, r) in self.0.iter_mut().zip(rhs.0) {
+            *l -= r;
+        }
+    }
+}
+impl<T, const DIMS: UnitDims, const N: usize> Neg for UnitVector<T, DIMS, N>
+where
+    T: Value + Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(self.0.map(Neg::neg))
+    }
+}
+impl<T, const DIMS: UnitDims, const N: usize> Mul<T> for UnitVector<T, DIMS, N>
+where
+    T: Value + Copy + Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self(self.0.map(|c| c * rhs))
+    }
+}
+impl<T, const DIMS: UnitDims, const N: usize> Div<T> for UnitVector<T, DIMS, N>
+where
+    T: Value + Copy + Div<Output = T>,
+{
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self(self.0.map(|c| c / rhs))
+    }
+}
+impl<T, const DIMS_L: UnitDims, const DIMS_R: UnitDims, const N: usize> Mul<UnitValue<T, DIMS_R>>
+    for UnitVector<T, DIMS_L, N>
+where
+    T: Value + Copy + Mul<Output = T>,
+    [(); DIMS_L.add(DIMS_R).convert_for_const()]: ,
+{
+    type Output = UnitVector<T, { DIMS_L.add(DIMS_R) }, N>;
+
+    fn mul(self, rhs: UnitValue<T, DIMS_R>) -> Self::Output {
+        let rhs = rhs.into_inner();
+        UnitVector(self.0.map(|c| c * rhs))
+    }
+}
+impl<T, const DIMS_L: UnitDims, const DIMS_R: UnitDims, const N: usize> Div<UnitValue<T, DIMS_R>>
+    for UnitVector<T, DIMS_L, N>
+where
+    T: Value + Copy + Div<Output = T>,
+    [(); DIMS_L.sub(DIMS_R).convert_for_const()]: ,
+{
+    type Output = UnitVector<T, { DIMS_L.sub(DIMS_R) }, N>;
+
+    fn div(self, rhs: UnitValue<T, DIMS_R>) -> Self::Output {
+        let rhs = rhs.into_inner();
+        UnitVector(self.0.map(|c| c / rhs))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::units::*;
+
+    #[test]
+    fn vector_test() {
+        let position = UnitVector3::<f64, { UnitDims::LENGTH }>::new([1.0, 2.0, 3.0]);
+        let offset =
+            UnitVector::from_values([Length::new(1.0), Length::new(1.0), Length::new(1.0)]);
+        assert_eq!([2.0, 3.0, 4.0], (position + offset).into_inner());
+        assert_eq!([0.0, 1.0, 2.0], (position - offset).into_inner());
+        assert_eq!([-2.0, -4.0, -6.0], (-position * 2.0).into_inner());
+        assert_eq!(Length::new(2.0), position.get(1));
+
+        let time: Time<f64> = UnitValue::new(2.0);
+        let velocity: UnitVector3<f64, { UnitDims::VELOCITY }> = position / time;
+        assert_eq!([0.5, 1.0, 1.5], velocity.into_inner());
+        let _: UnitVector3<f64, { UnitDims::LENGTH }> = velocity * time;
+
+        let force = UnitVector3::<f64, { UnitDims::FORCE }>::new([0.0, 0.0, 2.0]);
+        let work: Energy<f64> = position.dot(force);
+        assert_eq!(6.0, work.into_inner());
+        let torque: UnitVector3<f64, { UnitDims::ENERGY }> = position.cross(force);
+        assert_eq!([4.0, -2.0, 0.0], torque.into_inner());
+        #[cfg(feature = "std")]
+        assert_eq!(Length::new(5.0), UnitVector2::new([3.0, 4.0]).norm());
+    }
+}
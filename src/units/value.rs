@@ -1,4 +1,4 @@
-use std::num::*;
+use core::num::*;
 use crate::units::DisplayValue;
 use crate::units::sealed::Sealed;
 
@@ -0,0 +1,98 @@
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+use space_rpg::qty;
+use space_rpg::units::*;
+
+fn assert_close(expected: f64, actual: f64) {
+    assert!(
+        ((expected - actual) / expected).abs() < 1e-12,
+        "expected {}, got {}",
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn dimensionless_test() {
+    let value: UnitValue<f64, { UnitDims::DIMENSIONLESS }> = qty!(3);
+    assert_eq!(3.0, *value.value());
+}
+
+#[test]
+fn single_unit_test() {
+    let length: UnitValue<f64, { UnitDims::LENGTH }> = qty!(2.5 km);
+    assert_close(2.5e12, *length.value());
+    let mass: UnitValue<f64, { UnitDims::MASS }> = qty!(1 EarthMass);
+    assert_close(5.9722e30, *mass.value());
+}
+
+#[test]
+fn compound_unit_test() {
+    let acceleration: UnitValue<
+        f64,
+        {
+            UnitDims {
+                length: 1,
+                time: -2,
+                ..UnitDims::DIMENSIONLESS
+            }
+        },
+    > = qty!(9.81 m/s^2);
+    assert_close(9.81e-9, *acceleration.value());
+
+    let energy: UnitValue<
+        f64,
+        {
+            UnitDims {
+                mass: 1,
+                length: 2,
+                time: -2,
+                ..UnitDims::DIMENSIONLESS
+            }
+        },
+    > = qty!(4 kg m^2 s^-2);
+    assert_close(4e6, *energy.value());
+    let same_energy: UnitValue<
+        f64,
+        {
+            UnitDims {
+                mass: 1,
+                length: 2,
+                time: -2,
+                ..UnitDims::DIMENSIONLESS
+            }
+        },
+    > = qty!(4 kg*m*m/s/s);
+    assert_close(*energy.value(), *same_energy.value());
+
+    let frequency: UnitValue<
+        f64,
+        {
+            UnitDims {
+                time: -1,
+                ..UnitDims::DIMENSIONLESS
+            }
+        },
+    > = qty!(50 / s);
+    assert_close(50e-9, *frequency.value());
+}
+
+#[test]
+fn negative_test() {
+    let value: UnitValue<f64, { UnitDims::DIMENSIONLESS }> = qty!(-3);
+    assert_eq!(-3.0, *value.value());
+    let length: UnitValue<f64, { UnitDims::LENGTH }> = qty!(-2.5 km);
+    assert_close(-2.5e12, *length.value());
+    let velocity: UnitValue<
+        f64,
+        {
+            UnitDims {
+                length: 1,
+                time: -1,
+                ..UnitDims::DIMENSIONLESS
+            }
+        },
+    > = qty!(-3 m/s);
+    assert_close(-3.0, *velocity.value());
+}
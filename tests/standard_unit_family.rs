@@ -0,0 +1,174 @@
+#![feature(adt_const_params)]
+#![allow(incomplete_features)]
+
+use space_rpg::standard_unit_family;
+use space_rpg::units::*;
+
+standard_unit_family! {
+    pub enum Temperature: { UnitDims::TEMPERATURE } {
+        Millikelvin => "mK";
+        Kelvin => "K", Millikelvin * 1000;
+        Rankine => "°R", Millikelvin * 555.555_555_555_555_6;
+    }
+}
+
+standard_unit_family! {
+    pub enum Cosmic: { UnitDims::LENGTH } {
+        Nanometer => "nm";
+        Parsec => "pc", Nanometer * 30_856_775_814_913_673_000_000_000;
+        Petaparsec => "Ppc", Parsec * 1e15;
+    }
+}
+
+#[test]
+fn custom_family_test() {
+    assert_eq!(UnitDims::TEMPERATURE, Temperature::DIMS);
+    assert_eq!(Temperature::Millikelvin, Temperature::BASE_UNIT);
+    assert_eq!(
+        &[
+            Temperature::Millikelvin,
+            Temperature::Kelvin,
+            Temperature::Rankine
+        ],
+        Temperature::UNITS
+    );
+    assert_eq!("K", Temperature::Kelvin.symbol());
+
+    let kelvin: f64 = Temperature::Kelvin.amount_of_base_as();
+    assert_eq!(1000.0, kelvin);
+    let rankine: f64 = Temperature::Rankine.display_repr_as(5_000.0);
+    assert!((rankine - 9.0).abs() < 1e-12);
+}
+
+#[test]
+fn overflowing_family_test() {
+    // A petaparsec in nanometres doesn't fit in a `Factor` but is still exact.
+    assert!(Cosmic::PARSEC_FACTOR.as_integer().is_some());
+    assert_eq!(None, Cosmic::Petaparsec.checked_amount_of_base());
+    assert_eq!(
+        Err(UnitError::Overflow),
+        Cosmic::Petaparsec.try_amount_of_base()
+    );
+    assert_eq!(
+        parse_exact("30856775814913673e24").unwrap(),
+        Cosmic::Petaparsec.exact_amount_of_base()
+    );
+    let petaparsec: f64 = Cosmic::Petaparsec.amount_of_base_as();
+    assert_close(3.085_677_581_491_367e40, petaparsec, 1e-15);
+}
+
+#[test]
+fn standard_family_test() {
+    let minute: f64 = TimeUnit::Minute.amount_of_base_as();
+    assert_eq!(60e9, minute);
+    let decimeter: f64 = LengthUnit::Decimeter.amount_of_base_as();
+    assert_eq!(1e8, decimeter);
+    let ton: f64 = MassUnit::Ton.amount_of_base_as();
+    assert!((ton - 907_184_740.0).abs() < 1e-3);
+    assert_eq!("µA", ElectricCurrentUnit::Microampere.symbol());
+    assert_eq!("GSyr", StandardUnit::Time(TimeUnit::GSYear).symbol());
+}
+
+fn ratio<const DIMS: UnitDims, U: StandardUnitImpl<DIMS>>(unit: U, other: U) -> f64 {
+    let unit: f64 = unit.amount_of_base_as();
+    let other: f64 = other.amount_of_base_as();
+    unit / other
+}
+
+fn assert_close(expected: f64, actual: f64, tolerance: f64) {
+    assert!(
+        ((expected - actual) / expected).abs() < tolerance,
+        "expected {}, got {}",
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn spaceflight_units_test() {
+    assert_eq!(
+        Factor::from_decimal("1.609344").mul(LengthUnit::KILOMETER_FACTOR),
+        LengthUnit::MILE_FACTOR
+    );
+    assert_eq!(
+        Factor::integer(1852).mul(LengthUnit::METER_FACTOR),
+        LengthUnit::NAUTICALMILE_FACTOR
+    );
+    assert_eq!(
+        LengthUnit::PARSEC_FACTOR.mul(Factor::integer(1000)),
+        LengthUnit::KILOPARSEC_FACTOR
+    );
+    assert_eq!(
+        LengthUnit::KILOMETER_FACTOR.mul(Factor::integer(1000)),
+        LengthUnit::MEGAMETER_FACTOR
+    );
+    assert_eq!(
+        TimeUnit::SECOND_FACTOR.mul(Factor::integer(604_800)),
+        TimeUnit::WEEK_FACTOR
+    );
+    assert_eq!(
+        TimeUnit::HOUR_FACTOR.mul(Factor::integer(8766)),
+        TimeUnit::JULIANYEAR_FACTOR
+    );
+
+    assert_close(
+        206_264.806_247,
+        ratio(LengthUnit::Parsec, LengthUnit::AU),
+        1e-12,
+    );
+    assert_close(
+        3.261_563_777,
+        ratio(LengthUnit::Parsec, LengthUnit::LightYear),
+        1e-9,
+    );
+    assert_close(
+        109.076,
+        ratio(LengthUnit::SolarRadius, LengthUnit::EarthRadius),
+        1e-5,
+    );
+    assert_close(
+        60.27,
+        ratio(LengthUnit::LunarDistance, LengthUnit::EarthRadius),
+        1e-3,
+    );
+    assert_close(
+        0.997_269_566,
+        ratio(TimeUnit::SiderealDay, TimeUnit::Hour) / 24.0,
+        1e-9,
+    );
+    assert_close(
+        317.83,
+        ratio(MassUnit::JupiterMass, MassUnit::EarthMass),
+        1e-4,
+    );
+    assert_close(81.3, ratio(MassUnit::EarthMass, MassUnit::LunarMass), 1e-3);
+    // A mole of atomic mass units is a gram to within the 2019 SI redefinition.
+    assert_close(
+        1.0,
+        ratio(MassUnit::AtomicMassUnit, MassUnit::Gram) * 6.022_140_76e23,
+        1e-9,
+    );
+}
+
+#[test]
+fn round_trip_test() {
+    for unit in LengthUnit::UNITS {
+        for value in &[1.0, 0.25, 3e5] {
+            let base: f64 = unit.base_repr_as(*value);
+            let display: f64 = unit.display_repr_as(base);
+            assert_close(*value, display, 1e-12);
+            let exact = unit.exact_display_repr(
+                &unit.exact_base_repr(&ExactValue::from_float(*value).unwrap()),
+            );
+            assert_eq!(ExactValue::from_float(*value).unwrap(), exact);
+        }
+    }
+    for unit in TimeUnit::UNITS {
+        let base: f64 = unit.base_repr_as(2.5);
+        assert_close(2.5, unit.display_repr_as(base), 1e-12);
+    }
+    for unit in MassUnit::UNITS {
+        let base: f64 = unit.base_repr_as(2.5);
+        assert_close(2.5, unit.display_repr_as(base), 1e-12);
+    }
+}